@@ -32,7 +32,8 @@ pub(crate) fn fmt_as_annotate_snippets<T: ErrorType + ?Sized>(
     error: &T,
     opt: FormatOptions,
 ) -> String {
-    let primary_message = error.primary_message().to_string();
+    crate::span::debug_assert_in_bounds(error);
+    let primary_message = error.primary_message_verbose().to_string();
     let primary_labels = error.primary_labels();
     let code = error.code();
     let annotation_type = error.kind().as_annotate_snippets();
@@ -0,0 +1,122 @@
+use crate::{vec1, AdditionalKind, ErrorType, IterAdditional, LabelVec1};
+use alloc::{borrow::Cow, boxed::Box, string::String, vec::Vec};
+use core::fmt;
+
+/// An error carrying notes computed at runtime, see [`ErrorTypeExt::with_note`].
+///
+/// The notes follow the error's own [additional](ErrorType::additional) diagnostics, so every
+/// backend renders them in its footer.
+///
+/// [`ErrorTypeExt::with_note`]: crate::ErrorTypeExt::with_note
+pub struct Annotated<'a, T: ErrorType + ?Sized> {
+    error: &'a T,
+    notes: Vec<T::Message>,
+}
+
+impl<'a, T: ErrorType + ?Sized> Annotated<'a, T> {
+    pub(crate) fn new(error: &'a T, note: T::Message) -> Self {
+        Self {
+            error,
+            notes: Vec::from([note]),
+        }
+    }
+    /// Append another note.
+    pub fn with_note(mut self, note: impl Into<T::Message>) -> Self {
+        self.notes.push(note.into());
+        self
+    }
+    /// Get the wrapped error.
+    pub fn error(&self) -> &'a T {
+        self.error
+    }
+    /// Get the runtime notes.
+    pub fn notes(&self) -> &[T::Message] {
+        &self.notes
+    }
+}
+
+impl<T: ErrorType + ?Sized> fmt::Debug for Annotated<'_, T>
+where
+    T::Message: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Annotated")
+            .field("error", &self.error)
+            .field("notes", &self.notes)
+            .finish()
+    }
+}
+
+impl<T: ErrorType + ?Sized> fmt::Display for Annotated<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.error, f)
+    }
+}
+
+impl<T: ErrorType + ?Sized> core::error::Error for Annotated<'_, T>
+where
+    T::Message: fmt::Debug,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+impl<T: ErrorType + ?Sized> ErrorType for Annotated<'_, T>
+where
+    T::Message: fmt::Debug + Clone + 'static,
+    T::Label: From<T::Message> + 'static,
+    T::Span: 'static,
+{
+    type Span = T::Span;
+    type Kind = T::Kind;
+    type Message = T::Message;
+    type Label = T::Label;
+
+    fn kind(&self) -> Self::Kind {
+        self.error.kind()
+    }
+    fn number(&self) -> Cow<'_, str> {
+        self.error.number()
+    }
+    fn code(&self) -> Cow<'_, str> {
+        self.error.code()
+    }
+    fn code_with_kind(&self, kind: &Self::Kind) -> Cow<'_, str> {
+        self.error.code_with_kind(kind)
+    }
+    fn primary_span(&self) -> Option<Self::Span> {
+        self.error.primary_span()
+    }
+    fn primary_message(&self) -> Self::Message {
+        self.error.primary_message()
+    }
+    fn primary_message_verbose(&self) -> Self::Message {
+        self.error.primary_message_verbose()
+    }
+    fn primary_labels(&self) -> LabelVec1<Self::Span, Self::Label> {
+        self.error.primary_labels()
+    }
+    fn additional(&self) -> IterAdditional<Self> {
+        let notes = self.notes.clone().into_iter().map(|note| {
+            let label = vec1![(T::Span::default(), T::Label::from(note.clone()))];
+            (note, label, AdditionalKind::Note)
+        });
+        Box::new(self.error.additional().chain(notes))
+    }
+    fn severity_level(&self) -> u8 {
+        self.error.severity_level()
+    }
+    fn suggestion(&self) -> Option<(Self::Span, String)> {
+        self.error.suggestion()
+    }
+    fn message_template(&self) -> &'static str {
+        self.error.message_template()
+    }
+    fn primary_message_static(&self) -> Option<&'static str> {
+        self.error.primary_message_static()
+    }
+    fn primary_label_template(&self) -> &'static str {
+        self.error.primary_label_template()
+    }
+}
@@ -3,13 +3,36 @@ use alloc::{
     string::{String, ToString as _},
     vec::Vec,
 };
-use ariadne::{Config, Label, Report};
+use ariadne::{Color, Config, Label, Report};
 use core::fmt;
 use std::io;
 
-pub(crate) struct SpanWrapper<T>(T);
+/// An [`ariadne::Span`] over a [`Span`], for building custom ariadne reports.
+///
+/// The [`SourceId`](ariadne::Span::SourceId) is the [URI](Span::uri) of the span, so an
+/// [`ariadne::Cache`] for these spans is keyed by URI, e.g. one built by [`ariadne::sources`]
+/// from `(uri, source)` pairs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AriadneSpan<T>(T);
 
-impl<T: Span> ariadne::Span for SpanWrapper<T> {
+impl<T: Span> AriadneSpan<T> {
+    /// Wrap a span.
+    pub fn new(span: T) -> Self {
+        Self(span)
+    }
+    /// Get the wrapped span.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: Span> From<T> for AriadneSpan<T> {
+    fn from(span: T) -> Self {
+        Self(span)
+    }
+}
+
+impl<T: Span> ariadne::Span for AriadneSpan<T> {
     type SourceId = T::Uri;
 
     fn source(&self) -> &Self::SourceId {
@@ -32,22 +55,19 @@ struct Cache<T: ErrorType + ?Sized> {
     sources: Vec<SourceEntry<T>>,
 }
 
+/// One source per file, so that labels in several files all render.
 impl<T: ErrorType + ?Sized> FromIterator<T::Span> for Cache<T> {
     fn from_iter<I: IntoIterator<Item = T::Span>>(iter: I) -> Self {
-        let sources = iter
-            .into_iter()
-            .map(
-                |span| -> (
-                    <T::Span as Span>::Uri,
-                    ariadne::Source<<T::Span as Span>::Source>,
-                ) {
-                    (
-                        span.uri().clone(),
-                        ariadne::Source::from(span.source_text().clone()),
-                    )
-                },
-            )
-            .collect();
+        let mut sources: Vec<SourceEntry<T>> = Vec::new();
+        for span in iter {
+            if sources.iter().any(|(uri, _)| uri == span.uri()) {
+                continue;
+            }
+            sources.push((
+                span.uri().clone(),
+                ariadne::Source::from(span.source_text().clone()),
+            ));
+        }
         Self { sources }
     }
 }
@@ -75,6 +95,18 @@ impl<T: ErrorType + ?Sized> ariadne::Cache<<T::Span as Span>::Uri> for Cache<T>
     }
 }
 
+/// Color of primary labels for a report of the given kind, matching ariadne's own header color.
+///
+/// Ariadne drops label colors when [`Config::with_color`] is disabled.
+fn kind_color(kind: ariadne::ReportKind<'_>) -> Color {
+    match kind {
+        ariadne::ReportKind::Error => Color::Red,
+        ariadne::ReportKind::Warning => Color::Yellow,
+        ariadne::ReportKind::Advice => Color::Fixed(147),
+        ariadne::ReportKind::Custom(_, color) => color,
+    }
+}
+
 fn is_placeholder_span<S: Span>(span: &S) -> bool {
     span.start() == span.end() && span.start() == 0 && span.uri().to_string().is_empty()
 }
@@ -84,6 +116,7 @@ pub(crate) fn to_ariadne_report<T: ErrorType + ?Sized>(
     buf: &mut impl io::Write,
     config: Config,
 ) -> Result<(), io::Error> {
+    crate::span::debug_assert_in_bounds(error);
     let primary_labels = error.primary_labels();
     let primary_span = primary_labels.first().0.clone();
     let mut spans: Vec<T::Span> = Vec::new();
@@ -99,12 +132,18 @@ pub(crate) fn to_ariadne_report<T: ErrorType + ?Sized>(
         }
     }
     let cache: Cache<T> = Cache::from_iter(spans);
-    let mut builder = Report::build(error.kind().as_ariadne(), SpanWrapper(primary_span.clone()))
+    let report_kind = error.kind().as_ariadne();
+    let color = kind_color(report_kind);
+    let mut builder = Report::build(report_kind, AriadneSpan(primary_span.clone()))
         .with_code(error.code())
-        .with_message(error.primary_message())
+        .with_message(error.primary_message_verbose())
         .with_config(config);
     for (span, label) in primary_labels.iter().cloned() {
-        builder = builder.with_label(Label::new(SpanWrapper(span)).with_message(label));
+        builder = builder.with_label(
+            Label::new(AriadneSpan(span))
+                .with_message(label)
+                .with_color(color),
+        );
     }
     for (message, labels, kind) in error.additional() {
         match kind {
@@ -119,7 +158,7 @@ pub(crate) fn to_ariadne_report<T: ErrorType + ?Sized>(
                     if is_placeholder_span(&span) {
                         continue;
                     }
-                    builder = builder.with_label(Label::new(SpanWrapper(span)).with_message(label));
+                    builder = builder.with_label(Label::new(AriadneSpan(span)).with_message(label));
                 }
                 if !message.to_string().is_empty()
                     && message.to_string() != labels.first().1.to_string()
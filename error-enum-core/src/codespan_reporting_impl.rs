@@ -20,6 +20,7 @@ fn is_placeholder_span<S: Span>(span: &S) -> bool {
 pub(crate) fn to_codespan_diagnostic<T: ErrorType + ?Sized>(
     value: &T,
 ) -> (Diagnostic<usize>, Files<T>) {
+    crate::span::debug_assert_in_bounds(value);
     let mut files = SimpleFiles::new();
     let mut file_ids: Vec<(usize, T::Span)> = Vec::new();
     let mut resolve_file = |span: &T::Span| -> usize {
@@ -85,7 +86,7 @@ pub(crate) fn to_codespan_diagnostic<T: ErrorType + ?Sized>(
     let diagnostic = Diagnostic {
         severity: value.kind().as_codespan(),
         code: Some(value.code().into_owned()),
-        message: value.primary_message().to_string(),
+        message: value.primary_message_verbose().to_string(),
         labels,
         notes,
     };
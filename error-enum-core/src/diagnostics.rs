@@ -0,0 +1,187 @@
+use crate::{span, ErrorType, ErrorTypeExt, Kind, Span};
+#[cfg(feature = "std")]
+use crate::{OutputFormat, RenderOptions};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::{fmt, ops::Deref, slice};
+
+/// An ordered collection of errors reported by one run, e.g. all errors of a compilation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostics<T> {
+    items: Vec<T>,
+}
+
+impl<T> Diagnostics<T> {
+    /// Create an empty [`Diagnostics`].
+    pub const fn new() -> Self {
+        Self { items: Vec::new() }
+    }
+    /// Append an error.
+    pub fn push(&mut self, error: T) {
+        self.items.push(error);
+    }
+    /// Get the number of errors.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+    /// Check if there are no errors.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+    /// Iterate over the errors in insertion order.
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.items.iter()
+    }
+    /// Get the errors as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        &self.items
+    }
+    /// Convert into the underlying [`Vec`].
+    pub fn into_vec(self) -> Vec<T> {
+        self.items
+    }
+    /// Borrow the errors through [`Deref`], e.g. to render boxed
+    /// [`DynErrorType`](crate::DynErrorType)s, as `&dyn ErrorType` is an [`ErrorType`] itself.
+    pub fn as_deref(&self) -> Diagnostics<&T::Target>
+    where
+        T: Deref,
+    {
+        self.items.iter().map(Deref::deref).collect()
+    }
+}
+
+impl<T: ErrorType> Diagnostics<T> {
+    /// Count the errors by [kind](ErrorType::kind).
+    ///
+    /// Kinds without any error are absent from the map.
+    pub fn counts(&self) -> BTreeMap<T::Kind, usize>
+    where
+        T::Kind: Ord,
+    {
+        let mut counts = BTreeMap::new();
+        for error in &self.items {
+            *counts.entry(error.kind()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Get the most severe [kind](ErrorType::kind) among the errors, or [`None`] if there are
+    /// none, e.g. to choose the exit code of a CLI.
+    ///
+    /// Kinds are ordered from the most severe, like [`Kind::ALL`], so this is the least kind.
+    pub fn max_severity(&self) -> Option<T::Kind>
+    where
+        T::Kind: Ord,
+    {
+        self.items.iter().map(ErrorType::kind).min()
+    }
+
+    /// Sort the errors by URI, then line and column of the [primary span](ErrorType::primary_span),
+    /// then [code](ErrorType::code).
+    ///
+    /// Errors without a primary span, or with a placeholder one, come last. The sort is stable.
+    pub fn sort(&mut self)
+    where
+        <T::Span as Span>::Uri: Ord,
+    {
+        self.items.sort_by_cached_key(|error| {
+            let location = error
+                .primary_span()
+                .filter(|primary| !span::is_placeholder(primary))
+                .and(error.primary_location());
+            (location.is_none(), location, String::from(error.code()))
+        });
+    }
+
+    /// Render every error in `format` through `options` and write them to `w`, separated by
+    /// blank lines.
+    ///
+    /// `w` is flushed once at the end.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write_all<W: std::io::Write>(
+        &self,
+        w: &mut W,
+        format: OutputFormat,
+        options: RenderOptions,
+    ) -> std::io::Result<()> {
+        for (index, error) in self.items.iter().enumerate() {
+            if index != 0 {
+                w.write_all(b"\n")?;
+            }
+            let output = format.render(&error.with_render_options(options))?;
+            w.write_all(output.trim_end_matches('\n').as_bytes())?;
+            w.write_all(b"\n")?;
+        }
+        w.flush()
+    }
+}
+
+impl<T> Default for Diagnostics<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<Vec<T>> for Diagnostics<T> {
+    fn from(items: Vec<T>) -> Self {
+        Self { items }
+    }
+}
+
+impl<T> FromIterator<T> for Diagnostics<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            items: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> Extend<T> for Diagnostics<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.items.extend(iter);
+    }
+}
+
+impl<T> IntoIterator for Diagnostics<T> {
+    type Item = T;
+    type IntoIter = alloc::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Diagnostics<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+fn plural(n: usize, word: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if n == 1 {
+        write!(f, "{n} {word}")
+    } else {
+        write!(f, "{n} {word}s")
+    }
+}
+
+/// Summarize the collection as `"N errors, M warnings"`, followed by `", K notes"` when it has
+/// any [`Info`](Kind::Info), [`Note`](Kind::Note) or [`Help`](Kind::Help), counted together.
+impl<T: ErrorType<Kind = Kind>> fmt::Display for Diagnostics<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let counts = self.counts();
+        let count = |kind| counts.get(&kind).copied().unwrap_or(0);
+        plural(count(Kind::Error), "error", f)?;
+        f.write_str(", ")?;
+        plural(count(Kind::Warn), "warning", f)?;
+        let notes = count(Kind::Info) + count(Kind::Note) + count(Kind::Help);
+        if notes != 0 {
+            f.write_str(", ")?;
+            plural(notes, "note", f)?;
+        }
+        Ok(())
+    }
+}
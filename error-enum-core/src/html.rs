@@ -0,0 +1,82 @@
+use crate::{span::is_placeholder, AdditionalKind, DiagnosticKind, ErrorType, Indexer, Span};
+use alloc::string::{String, ToString};
+use core::fmt::{self, Write};
+
+struct HtmlStr<'a>(&'a str);
+
+impl fmt::Display for HtmlStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in self.0.chars() {
+            match c {
+                '<' => f.write_str("&lt;")?,
+                '>' => f.write_str("&gt;")?,
+                '&' => f.write_str("&amp;")?,
+                '"' => f.write_str("&quot;")?,
+                '\'' => f.write_str("&#39;")?,
+                c => f.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+fn write_snippet<S: Span>(out: &mut String, span: &S, label: &str) -> fmt::Result {
+    let (line, col) = span.start_line_col();
+    writeln!(
+        out,
+        "<span class=\"error-location\">{}:{line}:{col}</span>",
+        HtmlStr(&span.uri().to_string()),
+    )?;
+    let source = span.source_text().as_ref();
+    let (start, end) = span
+        .source_index()
+        .span_with_context_lines(span.start(), span.end(), 0, 0);
+    let text = |range: core::ops::Range<usize>| source.get(range).unwrap_or_default();
+    let after = text(span.end()..end);
+    let after = after.strip_suffix('\n').unwrap_or(after);
+    let after = after.strip_suffix('\r').unwrap_or(after);
+    writeln!(
+        out,
+        "<span class=\"error-source\">{}<mark class=\"error-span\">{}</mark>{}</span>",
+        HtmlStr(text(start..span.start())),
+        HtmlStr(text(span.range())),
+        HtmlStr(after),
+    )?;
+    writeln!(out, "<span class=\"error-label\">{}</span>", HtmlStr(label))
+}
+
+pub(crate) fn fmt_as_html<T: ErrorType + ?Sized>(error: &T) -> Result<String, fmt::Error> {
+    crate::span::debug_assert_in_bounds(error);
+    let kind = error.kind();
+    let mut out = String::new();
+    writeln!(
+        out,
+        "<pre class=\"diagnostic {kind}\"><span class=\"error-header\"><span class=\"error-kind\">{kind}</span>[<span class=\"error-code\">{}</span>]: <span class=\"error-message\">{}</span></span>",
+        HtmlStr(&error.code()),
+        HtmlStr(&error.primary_message_verbose().to_string()),
+        kind = HtmlStr(kind.name()),
+    )?;
+    for (span, label) in error.primary_labels().iter() {
+        if !is_placeholder(span) {
+            write_snippet(&mut out, span, &label.to_string())?;
+        }
+    }
+    for (message, labels, kind) in error.additional() {
+        let kind = match kind {
+            AdditionalKind::Note => "note",
+            AdditionalKind::Help => "help",
+        };
+        writeln!(
+            out,
+            "<span class=\"error-{kind}\">{kind}: {}</span>",
+            HtmlStr(&message.to_string()),
+        )?;
+        for (span, label) in labels.iter() {
+            if !is_placeholder(span) {
+                write_snippet(&mut out, span, &label.to_string())?;
+            }
+        }
+    }
+    out.write_str("</pre>")?;
+    Ok(out)
+}
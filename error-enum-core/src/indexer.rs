@@ -24,6 +24,64 @@ pub trait Indexer {
         context_lines_before: usize,
         context_lines_after: usize,
     ) -> (usize, usize);
+
+    /// Returns the start and the end of every line, including its line break.
+    ///
+    /// An empty line after a trailing line break is not included, so an empty text has no lines.
+    ///
+    /// The default implementation walks the text with [`line_span_at`](Self::line_span_at).
+    fn lines(&self) -> Box<dyn Iterator<Item = (usize, usize)> + '_> {
+        let mut pos = 0;
+        Box::new(core::iter::from_fn(move || {
+            let (start, end) = self.line_span_at(pos);
+            (start < end).then(|| {
+                pos = end;
+                (start, end)
+            })
+        }))
+    }
+
+    /// Returns the line number and the column in Unicode scalar values of `pos` in `source`.
+    ///
+    /// Unlike [`line_col_at`](Self::line_col_at), which counts bytes, every character takes one
+    /// column, so multibyte UTF-8 such as CJK text does not shift the column.
+    /// `source` must be the text this indexer was built from.
+    fn char_col_at(&self, pos: usize, source: &str) -> (usize, usize) {
+        let (line, col) = self.line_col_at(pos);
+        let col = source
+            .get(pos - col..pos)
+            .map_or(col, |prefix| prefix.chars().count());
+        (line, col)
+    }
+
+    /// Returns the line number and the column in UTF-16 code units of `pos` in `source`.
+    ///
+    /// Editors speaking LSP count columns this way, so characters outside the BMP, such as most
+    /// emoji, take two columns. `source` must be the text this indexer was built from.
+    fn utf16_col_at(&self, pos: usize, source: &str) -> (usize, usize) {
+        let (line, col) = self.line_col_at(pos);
+        let col = source
+            .get(pos - col..pos)
+            .map_or(col, |prefix| prefix.encode_utf16().count());
+        (line, col)
+    }
+
+    /// Returns the line number and the display column of `pos` in `source`.
+    ///
+    /// The column is the terminal width of the line before `pos`, so a caret drawn there lines up
+    /// under the character: wide CJK glyphs take two columns and combining marks take none.
+    /// `source` must be the text this indexer was built from.
+    #[cfg(feature = "unicode-width")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unicode-width")))]
+    fn display_col_at(&self, pos: usize, source: &str) -> (usize, usize) {
+        use unicode_width::UnicodeWidthStr;
+
+        let (line, col) = self.line_col_at(pos);
+        let col = source
+            .get(pos - col..pos)
+            .map_or(col, UnicodeWidthStr::width);
+        (line, col)
+    }
 }
 
 macro_rules! impl_indexable {
@@ -52,6 +110,23 @@ macro_rules! impl_indexable {
                     context_lines_after,
                 )
             }
+
+            fn lines(&self) -> Box<dyn Iterator<Item = (usize, usize)> + '_> {
+                T::lines(self)
+            }
+
+            fn char_col_at(&self, pos: usize, source: &str) -> (usize, usize) {
+                T::char_col_at(self, pos, source)
+            }
+
+            fn utf16_col_at(&self, pos: usize, source: &str) -> (usize, usize) {
+                T::utf16_col_at(self, pos, source)
+            }
+
+            #[cfg(feature = "unicode-width")]
+            fn display_col_at(&self, pos: usize, source: &str) -> (usize, usize) {
+                T::display_col_at(self, pos, source)
+            }
         }
     };
 }
@@ -176,6 +251,15 @@ impl Indexer for LineIndexer {
         };
         (start, end)
     }
+
+    fn lines(&self) -> Box<dyn Iterator<Item = (usize, usize)> + '_> {
+        let starts = core::iter::once(0).chain(self.0.iter().copied());
+        Box::new(
+            starts
+                .zip(self.0.iter().copied())
+                .filter(|(start, end)| start < end),
+        )
+    }
 }
 
 impl AsRef<[usize]> for LineIndexer {
@@ -183,3 +267,99 @@ impl AsRef<[usize]> for LineIndexer {
         &self.0
     }
 }
+
+/// An [`Indexer`] for preprocessed text, reporting positions in the original text.
+///
+/// Each edit is an `(offset, delta)` pair: from `offset` in the preprocessed text on, positions
+/// lie `delta` bytes further in the original text. Stripping `n` bytes at `offset` is
+/// `(offset, n)`, inserting `n` bytes is `(offset, -n)`. Positions inside inserted text map to
+/// the insertion point.
+///
+/// Positions passed in are preprocessed offsets, while the base indexer, the `source` of
+/// [`char_col_at`](Indexer::char_col_at) and [`utf16_col_at`](Indexer::utf16_col_at) and all
+/// returned spans refer to the original text.
+#[derive(Debug, PartialEq, Eq)]
+pub struct RemapIndexer<I> {
+    base: I,
+    /// Offset of each edit, with the total delta before and after it.
+    edits: Box<[(usize, isize, isize)]>,
+}
+
+impl<I: Indexer> RemapIndexer<I> {
+    /// Create a [`RemapIndexer`] over `base`, the indexer of the original text.
+    ///
+    /// `edits` must be sorted by offset.
+    pub fn new(base: I, edits: impl IntoIterator<Item = (usize, isize)>) -> Self {
+        let mut total = 0isize;
+        let edits: Box<[_]> = edits
+            .into_iter()
+            .map(|(offset, delta)| {
+                let before = total;
+                total += delta;
+                (offset, before, total)
+            })
+            .collect();
+        debug_assert!(
+            edits.is_sorted_by_key(|(offset, _, _)| *offset),
+            "edits must be sorted"
+        );
+        Self { base, edits }
+    }
+    /// Get the indexer of the original text.
+    pub fn base(&self) -> &I {
+        &self.base
+    }
+    /// Map a position in the preprocessed text to the original text.
+    pub fn original_offset(&self, pos: usize) -> usize {
+        let index = self.edits.partition_point(|(offset, _, _)| *offset <= pos);
+        let Some(&(offset, before, after)) = index.checked_sub(1).map(|i| &self.edits[i]) else {
+            return pos;
+        };
+        let shifted = pos.saturating_add_signed(after);
+        let insertion_point = offset.saturating_add_signed(before);
+        shifted.max(insertion_point)
+    }
+}
+
+impl<I: Indexer> Indexer for RemapIndexer<I> {
+    fn line_col_at(&self, pos: usize) -> (usize, usize) {
+        self.base.line_col_at(self.original_offset(pos))
+    }
+
+    fn line_span_at(&self, pos: usize) -> (usize, usize) {
+        self.base.line_span_at(self.original_offset(pos))
+    }
+
+    fn span_with_context_lines(
+        &self,
+        start: usize,
+        end: usize,
+        context_lines_before: usize,
+        context_lines_after: usize,
+    ) -> (usize, usize) {
+        self.base.span_with_context_lines(
+            self.original_offset(start),
+            self.original_offset(end),
+            context_lines_before,
+            context_lines_after,
+        )
+    }
+
+    /// Returns the lines of the original text.
+    fn lines(&self) -> Box<dyn Iterator<Item = (usize, usize)> + '_> {
+        self.base.lines()
+    }
+
+    fn char_col_at(&self, pos: usize, source: &str) -> (usize, usize) {
+        self.base.char_col_at(self.original_offset(pos), source)
+    }
+
+    fn utf16_col_at(&self, pos: usize, source: &str) -> (usize, usize) {
+        self.base.utf16_col_at(self.original_offset(pos), source)
+    }
+
+    #[cfg(feature = "unicode-width")]
+    fn display_col_at(&self, pos: usize, source: &str) -> (usize, usize) {
+        self.base.display_col_at(self.original_offset(pos), source)
+    }
+}
@@ -9,21 +9,43 @@
 #![no_std]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
-pub use alloc::{borrow::Cow, boxed::Box, format, string::String};
-use core::fmt;
-pub use indexer::{Indexer, LineIndexer};
+pub use alloc::{borrow::Cow, boxed::Box, format, string::String, vec::Vec};
+pub use annotated::Annotated;
+#[cfg(feature = "ariadne")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ariadne")))]
+pub use ariadne_impl::AriadneSpan;
+use core::{cmp::Ordering, fmt};
+pub use diagnostics::Diagnostics;
+pub use indexer::{Indexer, LineIndexer, RemapIndexer};
 pub use labels::{LabelVec1, SpannedLabel};
 pub use mitsein::vec1::{vec1, Vec1};
-pub use span::{SimpleSpan, Span};
+#[cfg(feature = "memmap2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "memmap2")))]
+pub use mmap_span::{MmapSource, MmapSpan};
+#[cfg(feature = "owo-colors")]
+#[cfg_attr(docsrs, doc(cfg(feature = "owo-colors")))]
+pub use owo_colors_impl::StyleSheet;
+#[cfg(feature = "proc-macro2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "proc-macro2")))]
+pub use proc_macro_span::ProcMacroSpan;
+pub use render::{CachedRender, OutputFormat, RenderOptions, Rendered};
+pub use source_map::SourceMap;
+pub use span::{OwnedSpan, SimpleSpan, SimpleSpanRc, Span};
 
 extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+mod annotated;
+mod diagnostics;
 mod indexer;
+#[cfg(any(feature = "std", feature = "rustc-json"))]
+mod json;
 #[cfg(any(feature = "annotate-snippets", feature = "codespan-reporting"))]
 mod label_groups;
 mod labels;
+mod render;
+mod source_map;
 mod span;
 
 #[cfg(feature = "annotate-snippets")]
@@ -32,8 +54,21 @@ mod annotate_snippets_impl;
 mod ariadne_impl;
 #[cfg(feature = "codespan-reporting")]
 mod codespan_reporting_impl;
+#[cfg(feature = "html")]
+mod html;
 #[cfg(feature = "miette")]
 mod miette_impl;
+#[cfg(feature = "memmap2")]
+mod mmap_span;
+#[cfg(feature = "owo-colors")]
+mod owo_colors_impl;
+#[cfg(feature = "proc-macro2")]
+mod proc_macro_span;
+#[cfg(feature = "rustc-json")]
+mod rustc_json;
+#[cfg(feature = "testing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub mod testing;
 
 /// Kind of an additional diagnostic.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -55,6 +90,10 @@ pub type IterAdditional<T> = Box<
     >,
 >;
 
+/// A type-erased [`ErrorType`] with [`String`] messages and labels, see [`ErrorTypeExt::boxed`].
+pub type DynErrorType<'a, S, K = Kind> =
+    dyn ErrorType<Span = S, Kind = K, Message = String, Label = String> + 'a;
+
 /// Trait for diagnostic severity / kind types used by [`ErrorType`].
 ///
 /// Implement this for custom kind enums. Each enabled backend feature requires an explicit
@@ -63,6 +102,42 @@ pub trait DiagnosticKind: Copy + Clone + Default {
     /// Error-code prefix, e.g. `"E"` / `"W"` / `"B"`.
     fn code_prefix(&self) -> &str;
 
+    /// The kind to render this kind as when warnings are promoted to errors, see
+    /// [`RenderOptions::warnings_as_errors`].
+    ///
+    /// Default: `*self`, i.e. the kind is never promoted.
+    fn warning_as_error(&self) -> Self {
+        *self
+    }
+
+    /// Lowercase name of the kind, e.g. `"error"` or `"warning"`.
+    ///
+    /// Default: `"error"`.
+    fn name(&self) -> &str {
+        "error"
+    }
+
+    /// Fine-grained severity of the kind: `0` error, `1` warning, `2` note, `3` none.
+    ///
+    /// Default: from [`name`](Self::name), `1` for `"warning"`, `2` for `"info"`, `"note"` and
+    /// `"help"`, and `0` otherwise.
+    fn severity_level(&self) -> u8 {
+        match self.name() {
+            "warning" => 1,
+            "info" | "note" | "help" => 2,
+            _ => 0,
+        }
+    }
+
+    /// The `level` of a rustc JSON diagnostic, e.g. `"error"` or `"warning"`.
+    ///
+    /// Default: [`name`](Self::name).
+    #[cfg(feature = "rustc-json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rustc-json")))]
+    fn rustc_level(&self) -> &str {
+        self.name()
+    }
+
     /// Convert to annotate-snippets annotation type.
     #[cfg(feature = "annotate-snippets")]
     #[cfg_attr(docsrs, doc(cfg(feature = "annotate-snippets")))]
@@ -84,22 +159,34 @@ pub trait DiagnosticKind: Copy + Clone + Default {
     fn as_miette(&self) -> miette::Severity;
 }
 
-/// Built-in diagnostic kind (`Error` / `Warn`).
-#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+/// Built-in diagnostic kind (`Error` / `Warn` / `Info` / `Note` / `Help`).
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Kind {
     /// Error kind.
     #[default]
     Error,
     /// Warning kind.
     Warn,
+    /// Informational kind.
+    Info,
+    /// Note kind.
+    Note,
+    /// Help kind.
+    Help,
 }
 
 impl Kind {
+    /// All kinds, from the most to the least severe.
+    pub const ALL: &'static [Kind] = &[Kind::Error, Kind::Warn, Kind::Info, Kind::Note, Kind::Help];
+
     /// Get short representation of the [Kind].
     pub fn short_str(&self) -> &'static str {
         match self {
             Kind::Error => "E",
             Kind::Warn => "W",
+            Kind::Info => "I",
+            Kind::Note => "N",
+            Kind::Help => "H",
         }
     }
 }
@@ -109,11 +196,40 @@ impl DiagnosticKind for Kind {
         self.short_str()
     }
 
+    fn warning_as_error(&self) -> Self {
+        match self {
+            Kind::Warn => Kind::Error,
+            kind => *kind,
+        }
+    }
+
+    fn name(&self) -> &str {
+        match self {
+            Kind::Error => "error",
+            Kind::Warn => "warning",
+            Kind::Info => "info",
+            Kind::Note => "note",
+            Kind::Help => "help",
+        }
+    }
+
+    #[cfg(feature = "rustc-json")]
+    fn rustc_level(&self) -> &str {
+        match self {
+            // rustc has no info level.
+            Kind::Info => "note",
+            kind => kind.name(),
+        }
+    }
+
     #[cfg(feature = "annotate-snippets")]
     fn as_annotate_snippets(&self) -> annotate_snippets::snippet::AnnotationType {
         match self {
             Kind::Error => annotate_snippets::snippet::AnnotationType::Error,
             Kind::Warn => annotate_snippets::snippet::AnnotationType::Warning,
+            Kind::Info => annotate_snippets::snippet::AnnotationType::Info,
+            Kind::Note => annotate_snippets::snippet::AnnotationType::Note,
+            Kind::Help => annotate_snippets::snippet::AnnotationType::Help,
         }
     }
 
@@ -122,6 +238,9 @@ impl DiagnosticKind for Kind {
         match self {
             Kind::Error => ariadne::ReportKind::Error,
             Kind::Warn => ariadne::ReportKind::Warning,
+            Kind::Info => ariadne::ReportKind::Advice,
+            Kind::Note => ariadne::ReportKind::Custom("Note", ariadne::Color::Fixed(147)),
+            Kind::Help => ariadne::ReportKind::Custom("Help", ariadne::Color::Cyan),
         }
     }
 
@@ -130,6 +249,8 @@ impl DiagnosticKind for Kind {
         match self {
             Kind::Error => codespan_reporting::diagnostic::Severity::Error,
             Kind::Warn => codespan_reporting::diagnostic::Severity::Warning,
+            Kind::Info | Kind::Note => codespan_reporting::diagnostic::Severity::Note,
+            Kind::Help => codespan_reporting::diagnostic::Severity::Help,
         }
     }
 
@@ -138,6 +259,19 @@ impl DiagnosticKind for Kind {
         match self {
             Kind::Error => miette::Severity::Error,
             Kind::Warn => miette::Severity::Warning,
+            Kind::Info | Kind::Note | Kind::Help => miette::Severity::Advice,
+        }
+    }
+}
+
+#[cfg(feature = "log")]
+#[cfg_attr(docsrs, doc(cfg(feature = "log")))]
+impl From<Kind> for log::Level {
+    fn from(kind: Kind) -> Self {
+        match kind {
+            Kind::Error => log::Level::Error,
+            Kind::Warn => log::Level::Warn,
+            Kind::Info | Kind::Note | Kind::Help => log::Level::Info,
         }
     }
 }
@@ -170,11 +304,23 @@ pub trait ErrorType: core::error::Error {
     /// Default: [`DiagnosticKind::code_prefix`] concatenated with [`number`](Self::number)
     /// as [`Cow::Owned`] (e.g. `"E0"`, `"W1"`; nested merge yields `"E0123"`).
     fn code(&self) -> Cow<'_, str> {
-        Cow::Owned(alloc::format!(
-            "{}{}",
-            self.kind().code_prefix(),
-            self.number()
-        ))
+        self.code_with_kind(&self.kind())
+    }
+    /// Get the code the error would have with the kind `kind`, e.g. when a warning is reported
+    /// as an error.
+    ///
+    /// Default: [`DiagnosticKind::code_prefix`] of `kind` concatenated with
+    /// [`number`](Self::number). The derive / `error_type!` macros put the `code_base` first,
+    /// followed by the prefix only with `#[diag(kind_prefix)]`.
+    fn code_with_kind(&self, kind: &Self::Kind) -> Cow<'_, str> {
+        Cow::Owned(alloc::format!("{}{}", kind.code_prefix(), self.number()))
+    }
+    /// Compare the [code](Self::code) of two errors lexicographically.
+    fn code_cmp(&self, other: &Self) -> Ordering
+    where
+        Self: Sized,
+    {
+        self.code().cmp(&other.code())
     }
     /// Get the primary span of the error.
     ///
@@ -182,6 +328,12 @@ pub trait ErrorType: core::error::Error {
     fn primary_span(&self) -> Option<Self::Span>;
     /// Get the primary message of the error.
     fn primary_message(&self) -> Self::Message;
+    /// Get the verbose primary message of the error, used by the full renderers.
+    ///
+    /// Default: [`primary_message`](Self::primary_message).
+    fn primary_message_verbose(&self) -> Self::Message {
+        self.primary_message()
+    }
     /// Get the primary labels of the error.
     ///
     /// Index `0` is the primary span label. Further entries are secondary span labels on the
@@ -198,6 +350,71 @@ pub trait ErrorType: core::error::Error {
     /// Each item is one note or help unit. [`LabelVec1`] index `0` is that unit's anchor label.
     /// Backend renderers group labels with the same source text into one slice or file.
     fn additional(&self) -> IterAdditional<Self>;
+
+    /// Get the fine-grained severity of the error: `0` error, `1` warning, `2` note, `3` none.
+    ///
+    /// Machine-readable outputs use it in place of the [kind](Self::kind), which stays coarse.
+    ///
+    /// Default: [`DiagnosticKind::severity_level`] of the kind. The derive / `error_type!` macros
+    /// return the variant's `level`.
+    fn severity_level(&self) -> u8 {
+        self.kind().severity_level()
+    }
+
+    /// Get the suggested replacement of the error: the span to replace and the text to put there.
+    ///
+    /// Default: [`None`]. The derive / `error_type!` macros return the variant's `suggest` text.
+    fn suggestion(&self) -> Option<(Self::Span, String)> {
+        None
+    }
+
+    /// Get the raw format string of the primary message, e.g. `"{path} not found."`.
+    ///
+    /// Default: `""`. The derive / `error_type!` macros return the variant's `msg` literal.
+    fn message_template(&self) -> &'static str {
+        ""
+    }
+    /// Get the primary message if it is fixed text, e.g. `"access denied"`, so that callers can
+    /// skip the allocation of [`primary_message`](Self::primary_message).
+    ///
+    /// Default: [`None`]. The derive / `error_type!` macros return the variant's `msg` when it
+    /// has no placeholders.
+    fn primary_message_static(&self) -> Option<&'static str> {
+        None
+    }
+    /// Get the kind and [number](Self::number) of every variant, in declaration order, so that
+    /// codes can be listed without an instance.
+    ///
+    /// Default: empty. The derive / `error_type!` macros list every variant, expanding nested
+    /// ones through this method of the inner error type.
+    fn kinds_and_numbers() -> alloc::vec::Vec<(Self::Kind, String)>
+    where
+        Self: Sized,
+    {
+        alloc::vec::Vec::new()
+    }
+    /// Get the [code](Self::code) of every variant, in declaration order, so that codes can be
+    /// checked without an instance, e.g. for clashes between two error types.
+    ///
+    /// Default: the [prefix](DiagnosticKind::code_prefix) of each kind followed by the number,
+    /// from [`kinds_and_numbers`](Self::kinds_and_numbers). The derive / `error_type!` macros put
+    /// the `code_base` first.
+    fn all_codes() -> alloc::vec::Vec<String>
+    where
+        Self: Sized,
+    {
+        Self::kinds_and_numbers()
+            .into_iter()
+            .map(|(kind, number)| alloc::format!("{}{}", kind.code_prefix(), number))
+            .collect()
+    }
+    /// Get the raw format string of the primary label.
+    ///
+    /// Default: `""`. The derive / `error_type!` macros return the variant's `label` literal,
+    /// or its `msg` literal when the label falls back to the message.
+    fn primary_label_template(&self) -> &'static str {
+        ""
+    }
 }
 
 impl<T: ErrorType + ?Sized> ErrorType for &T {
@@ -219,6 +436,10 @@ impl<T: ErrorType + ?Sized> ErrorType for &T {
         (*self).code()
     }
     #[inline]
+    fn code_with_kind(&self, kind: &Self::Kind) -> Cow<'_, str> {
+        (*self).code_with_kind(kind)
+    }
+    #[inline]
     fn primary_span(&self) -> Option<Self::Span> {
         (*self).primary_span()
     }
@@ -227,6 +448,10 @@ impl<T: ErrorType + ?Sized> ErrorType for &T {
         (*self).primary_message()
     }
     #[inline]
+    fn primary_message_verbose(&self) -> Self::Message {
+        (*self).primary_message_verbose()
+    }
+    #[inline]
     fn primary_labels(&self) -> LabelVec1<Self::Span, Self::Label> {
         (*self).primary_labels()
     }
@@ -240,6 +465,83 @@ impl<T: ErrorType + ?Sized> ErrorType for &T {
     fn additional(&self) -> IterAdditional<Self> {
         (*self).additional()
     }
+    #[inline]
+    fn severity_level(&self) -> u8 {
+        (*self).severity_level()
+    }
+    #[inline]
+    fn suggestion(&self) -> Option<(Self::Span, String)> {
+        (*self).suggestion()
+    }
+
+    #[inline]
+    fn message_template(&self) -> &'static str {
+        (*self).message_template()
+    }
+    #[inline]
+    fn primary_message_static(&self) -> Option<&'static str> {
+        (*self).primary_message_static()
+    }
+    #[inline]
+    fn primary_label_template(&self) -> &'static str {
+        (*self).primary_label_template()
+    }
+}
+
+/// Sort errors by their [code](ErrorType::code), see [`ErrorType::code_cmp`].
+///
+/// The sort is stable, so errors sharing a code keep their relative order.
+pub fn sort_by_code<T: ErrorType>(errors: &mut [T]) {
+    errors.sort_by(T::code_cmp);
+}
+
+/// The `kind[code]` tag opening the header line of `error`, e.g. `error[E00]`, with `code`
+/// written in place of its code, e.g. wrapped in a hyperlink.
+pub(crate) fn header_tag<T: ErrorType + ?Sized>(error: &T, code: &dyn fmt::Display) -> String {
+    alloc::format!("{}[{code}]", error.kind().name())
+}
+
+/// The header line `kind[code]: message` of an error, from its [tag](header_tag) and message,
+/// each possibly styled.
+pub(crate) fn header(tag: impl fmt::Display, message: impl fmt::Display) -> String {
+    alloc::format!("{tag}: {message}")
+}
+
+/// The [compact form](ErrorTypeExt::fmt_compact) of `error`, with `code` written in place of
+/// its code, e.g. wrapped in a hyperlink.
+fn compact<T: ErrorType + ?Sized>(error: &T, code: &dyn fmt::Display) -> String {
+    let mut line = header(header_tag(error, code), error.primary_message());
+    if let Some((uri, row, col)) = error.primary_location() {
+        line += &alloc::format!(" ({uri}:{row}:{col})");
+    }
+    line
+}
+
+/// Non-empty messages of the additional units of `error` of the given kind.
+fn additional_messages<T: ErrorType + ?Sized>(
+    error: &T,
+    kind: AdditionalKind,
+) -> alloc::vec::Vec<String> {
+    use alloc::string::ToString as _;
+
+    error
+        .additional()
+        .filter(|(_, _, unit_kind)| *unit_kind == kind)
+        .map(|(message, _, _)| message.to_string())
+        .filter(|message| !message.is_empty())
+        .collect()
+}
+
+/// Whether the terminal should get OSC 8 hyperlinks, consulted by
+/// [`ErrorTypeExt::fmt_compact_with_link`].
+///
+/// Returns `false` when `NO_COLOR` is set to a non-empty value or `TERM` is `dumb`.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn hyperlinks_enabled() -> bool {
+    let no_color = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+    let dumb = std::env::var_os("TERM").is_some_and(|term| term == "dumb");
+    !no_color && !dumb
 }
 
 /// Conversion to other diagnostic types.
@@ -250,6 +552,7 @@ impl<T: ErrorType + ?Sized> ErrorType for &T {
 /// - [ariadne]
 /// - [codespan-reporting]
 /// - [miette]
+/// - [rustc JSON]
 ///
 /// ## Label rendering
 ///
@@ -285,7 +588,236 @@ impl<T: ErrorType + ?Sized> ErrorType for &T {
 /// [ariadne]: https://docs.rs/ariadne/0.6.0/ariadne/
 /// [codespan-reporting]: https://docs.rs/codespan-reporting/0.13.1/codespan_reporting/
 /// [miette]: https://docs.rs/miette/7.6.0/miette/
+/// [rustc JSON]: https://doc.rust-lang.org/rustc/json.html
 pub trait ErrorTypeExt: ErrorType {
+    /// Get the URI, one-based line and one-based character column of the
+    /// [primary span](ErrorType::primary_span), if any.
+    fn primary_location(&self) -> Option<(<Self::Span as Span>::Uri, usize, usize)> {
+        let span = self.primary_span()?;
+        let (line, col) = span.start_line_col();
+        Some((span.uri().clone(), line, col))
+    }
+
+    /// Get the source text covered by the [primary span](ErrorType::primary_span), if any.
+    ///
+    /// The range is clamped to the source text. Returns `None` for placeholder spans and for
+    /// ranges that do not fall on character boundaries.
+    fn primary_span_text(&self) -> Option<String> {
+        let span = self.primary_span()?;
+        if span::is_placeholder(&span) {
+            return None;
+        }
+        let end = span.end().min(span.source_len());
+        let start = span.start().min(end);
+        span.source_text()
+            .as_ref()
+            .get(start..end)
+            .map(String::from)
+    }
+
+    /// Get the secondary spans of the error and their labels, i.e. every
+    /// [primary label](ErrorType::primary_labels) after the first one.
+    ///
+    /// The derive / `error_type!` macros fill them from fields marked with
+    /// `#[diag(span, label = "...")]` after the primary span, or with `#[diag(label("..."))]`.
+    fn secondary_spans(&self) -> alloc::vec::Vec<(Self::Span, String)> {
+        use alloc::string::ToString as _;
+
+        self.primary_labels()
+            .into_iter()
+            .skip(1)
+            .map(|(span, label)| (span, label.to_string()))
+            .collect()
+    }
+
+    /// Get the help text of the error: the non-empty messages of every
+    /// [help](AdditionalKind::Help) in [`additional`](ErrorType::additional), one per line.
+    ///
+    /// The derive / `error_type!` macros fill them from `#[diag(help("..."))]` on the variant,
+    /// its prefixes and its fields, interpolated like `msg`.
+    fn help(&self) -> Option<String> {
+        let parts = additional_messages(self, AdditionalKind::Help);
+        (!parts.is_empty()).then(|| parts.join("\n"))
+    }
+
+    /// Get the non-empty messages of every [note](AdditionalKind::Note) in
+    /// [`additional`](ErrorType::additional), in order.
+    ///
+    /// The derive / `error_type!` macros fill them from `#[diag(note("..."))]` on the variant,
+    /// its prefixes and its fields, interpolated like `msg`.
+    fn notes(&self) -> alloc::vec::Vec<String> {
+        additional_messages(self, AdditionalKind::Note)
+    }
+
+    /// Format the error as a single line, e.g. `error[E00]: message (main.rs:1:5)`.
+    ///
+    /// The location is omitted when there is no [primary span](ErrorType::primary_span).
+    fn fmt_compact(&self) -> String {
+        compact(self, &self.code())
+    }
+
+    /// Format the error as a single line without location, e.g. `error [E00] message`, for
+    /// summary tables of many errors.
+    fn one_line(&self) -> String {
+        alloc::format!(
+            "{} [{}] {}",
+            self.kind().name(),
+            self.code(),
+            self.primary_message()
+        )
+    }
+
+    /// Format the error as [`fmt_compact`](Self::fmt_compact) does, with the code wrapped in an
+    /// OSC 8 terminal hyperlink to `doc_url`.
+    ///
+    /// `{code}` in `doc_url` is replaced with the code, e.g. `https://example.com/errors/{code}`.
+    /// With the `std` feature, this falls back to [`fmt_compact`](Self::fmt_compact) unless
+    /// [`hyperlinks_enabled`] allows links; without it, the link is always written.
+    fn fmt_compact_with_link(&self, doc_url: &str) -> String {
+        #[cfg(feature = "std")]
+        if !hyperlinks_enabled() {
+            return self.fmt_compact();
+        }
+        let code = self.code();
+        let url = doc_url.replace("{code}", &code);
+        compact(
+            self,
+            &alloc::format!("\x1b]8;;{url}\x1b\\{code}\x1b]8;;\x1b\\"),
+        )
+    }
+
+    /// Format the error in the GNU style, e.g. `main.rs:1:5: error: message`, as understood by
+    /// editors and `errorformat`.
+    ///
+    /// The location is omitted when there is no [primary span](ErrorType::primary_span) or it
+    /// is a placeholder.
+    fn fmt_as_gnu(&self) -> String {
+        let location = self
+            .primary_span()
+            .filter(|span| !span::is_placeholder(span))
+            .and(self.primary_location());
+        match location {
+            Some((uri, row, col)) => alloc::format!(
+                "{uri}:{row}:{col}: {}: {}",
+                self.kind().name(),
+                self.primary_message()
+            ),
+            None => alloc::format!("{}: {}", self.kind().name(), self.primary_message()),
+        }
+    }
+
+    /// Format the error as plain text, with the lines around the
+    /// [primary span](ErrorType::primary_span) behind a line-number gutter and a caret line
+    /// under the span, e.g.
+    ///
+    /// ```text
+    /// error[E00]: undefined variable
+    ///  --> main.rs:2:9
+    ///   |
+    /// 2 | let x = y;
+    ///   |         ^ not found
+    /// ```
+    ///
+    /// `before` and `after` lines of context are shown around the span, and the gutter is as
+    /// wide as the largest line number shown. Only the first line is written without a primary
+    /// span or with a placeholder one.
+    ///
+    /// With the `unicode-width` feature, the caret line follows the terminal width of the text,
+    /// so carets land under wide glyphs such as CJK; otherwise every character takes one column.
+    fn fmt_as_plain_with_gutter(&self, before: usize, after: usize) -> String {
+        use alloc::string::ToString as _;
+
+        span::debug_assert_in_bounds(self);
+        let mut out = header(header_tag(self, &self.code()), self.primary_message());
+        out.push('\n');
+        let labels = self.primary_labels();
+        let (primary, label) = labels.first();
+        if span::is_placeholder(primary) {
+            return out;
+        }
+        out += &span::gutter_snippet(primary, &label.to_string(), before, after);
+        out
+    }
+
+    /// Emit the error through the [`log`] facade as its [compact form](Self::fmt_compact).
+    ///
+    /// The level follows the [severity level](ErrorType::severity_level): errors are logged
+    /// at [`Error`](log::Level::Error), warnings at [`Warn`](log::Level::Warn), notes at
+    /// [`Info`](log::Level::Info) and anything else at [`Debug`](log::Level::Debug).
+    #[cfg(feature = "log")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "log")))]
+    fn log(&self) {
+        let level = match self.severity_level() {
+            0 => log::Level::Error,
+            1 => log::Level::Warn,
+            2 => log::Level::Info,
+            _ => log::Level::Debug,
+        };
+        log::log!(level, "{}", self.fmt_compact());
+    }
+
+    /// Write the error as a single-line JSON object, e.g.
+    /// `{"code":"E00","kind":"error","message":"...","uri":"main.rs","start":4,"end":9}`.
+    ///
+    /// `uri`, `start` and `end` describe the [primary span](ErrorType::primary_span), and are
+    /// `null` without one. Unlike [`fmt_as_rustc_json`](Self::fmt_as_rustc_json), this needs
+    /// no feature besides `std`.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn write_json(&self, w: &mut impl std::io::Write) -> std::io::Result<()> {
+        use alloc::string::ToString as _;
+        use json::JsonStr;
+
+        write!(
+            w,
+            "{{\"code\":{},\"kind\":{},\"message\":{},",
+            JsonStr(&self.code()),
+            JsonStr(self.kind().name()),
+            JsonStr(&self.primary_message().to_string()),
+        )?;
+        match self
+            .primary_span()
+            .filter(|span| !span::is_placeholder(span))
+        {
+            Some(span) => write!(
+                w,
+                "\"uri\":{},\"start\":{},\"end\":{}}}",
+                JsonStr(&span.uri().to_string()),
+                span.start(),
+                span.end(),
+            ),
+            None => w.write_all(b"\"uri\":null,\"start\":null,\"end\":null}"),
+        }
+    }
+
+    /// Erase the concrete type of the error, e.g. to report errors of several error types
+    /// sharing a span type in one list.
+    ///
+    /// Methods of [`ErrorTypeExt`] can be called on the box directly. To render a list of boxes,
+    /// collect them into [`Diagnostics`] and render [`Diagnostics::as_deref`].
+    fn boxed<'a>(self) -> Box<DynErrorType<'a, Self::Span, Self::Kind>>
+    where
+        Self: ErrorType<Message = String, Label = String> + Sized + 'a,
+    {
+        Box::new(self)
+    }
+
+    /// View the error through [`RenderOptions`].
+    ///
+    /// [`kind`](ErrorType::kind) of the error itself is unchanged; the returned [`Rendered`]
+    /// reports the adjusted kind and code to every backend.
+    fn with_render_options(&self, options: RenderOptions) -> Rendered<'_, Self> {
+        Rendered::new(self, options)
+    }
+
+    /// Attach a note computed at runtime, e.g. a suggestion.
+    ///
+    /// The returned [`Annotated`] renders the note after the notes and helps of the error
+    /// itself; chain [`Annotated::with_note`] for more notes.
+    fn with_note(&self, note: impl Into<Self::Message>) -> Annotated<'_, Self> {
+        Annotated::new(self, note.into())
+    }
+
     /// Format the error as an [annotate snippet].
     ///
     /// [annotate snippet]: https://docs.rs/annotate-snippets/0.9.1/annotate_snippets/snippet/struct.Snippet.html
@@ -350,6 +882,10 @@ pub trait ErrorTypeExt: ErrorType {
     }
     /// Format the error as an [Codespan diagnostic] with [Codespan config].
     ///
+    /// The [code](ErrorType::code) is part of the header, e.g. `error[E01]: message`, styled
+    /// like the severity by the `header_*` [styles](codespan_reporting::term::Styles), as codespan
+    /// has no separate style for it.
+    ///
     /// [Codespan diagnostic]: https://docs.rs/codespan-reporting/0.13.1/codespan_reporting/diagnostic/struct.Diagnostic.html
     /// [Codespan config]: https://docs.rs/codespan-reporting/0.13.1/codespan_reporting/term/config/struct.Config.html
     #[cfg(feature = "codespan-reporting")]
@@ -361,6 +897,25 @@ pub trait ErrorTypeExt: ErrorType {
     ) -> Result<String, codespan_reporting::files::Error> {
         codespan_reporting_impl::fmt_as_codespan_diagnostic(self, config, styles)
     }
+    /// Format the error as an [Codespan diagnostic] with [Codespan config] and [Codespan styles]
+    /// chosen by the [kind](ErrorType::kind) of the error, e.g. to color warnings differently.
+    ///
+    /// `styles_by_kind` returning `None` formats without colors, like
+    /// [`fmt_as_codespan_diagnostic_with`](Self::fmt_as_codespan_diagnostic_with).
+    ///
+    /// [Codespan diagnostic]: https://docs.rs/codespan-reporting/0.13.1/codespan_reporting/diagnostic/struct.Diagnostic.html
+    /// [Codespan config]: https://docs.rs/codespan-reporting/0.13.1/codespan_reporting/term/config/struct.Config.html
+    /// [Codespan styles]: https://docs.rs/codespan-reporting/0.13.1/codespan_reporting/term/struct.Styles.html
+    #[cfg(feature = "codespan-reporting")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "codespan-reporting")))]
+    fn fmt_as_codespan_diagnostic_with_styles<'s>(
+        &self,
+        config: codespan_reporting::term::Config,
+        styles_by_kind: impl FnOnce(Self::Kind) -> Option<&'s codespan_reporting::term::Styles>,
+    ) -> Result<String, codespan_reporting::files::Error> {
+        let styles = styles_by_kind(self.kind());
+        codespan_reporting_impl::fmt_as_codespan_diagnostic(self, config, styles)
+    }
 
     /// Convert the error to a [Miette diagnostic].
     ///
@@ -386,6 +941,141 @@ pub trait ErrorTypeExt: ErrorType {
     {
         miette_impl::Wrapper::new(self).fmt_with(handler)
     }
+
+    /// Format the error as one line of [rustc JSON diagnostic], as emitted by
+    /// `rustc --error-format=json`.
+    ///
+    /// Byte offsets map to one-based lines and character columns through
+    /// [`Span::source_index`]. Notes and helps become children.
+    ///
+    /// [rustc JSON diagnostic]: https://doc.rust-lang.org/rustc/json.html#diagnostics
+    #[cfg(feature = "rustc-json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rustc-json")))]
+    fn fmt_as_rustc_json(&self) -> Result<String, fmt::Error> {
+        rustc_json::fmt_as_rustc_json(self)
+    }
+
+    /// Format the error as an HTML `<pre>` block, for web-based error browsers.
+    ///
+    /// Each labeled span shows its location and source line, with the spanned text wrapped in
+    /// `<mark class="error-span">`. Other elements carry `error-*` classes for styling, e.g.
+    /// `error-code` and `error-message`. Source text and messages are HTML-escaped.
+    #[cfg(feature = "html")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "html")))]
+    fn fmt_as_html(&self) -> Result<String, fmt::Error> {
+        html::fmt_as_html(self)
+    }
+
+    /// Format the error as [`fmt_compact`](Self::fmt_compact) does, styled with the default
+    /// [`StyleSheet`] through [`owo_colors`].
+    ///
+    /// Styles are only applied when stderr supports colors, which honors `NO_COLOR` and
+    /// `FORCE_COLOR`; see [`owo_colors::set_override`] to force them on or off.
+    #[cfg(feature = "owo-colors")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "owo-colors")))]
+    fn fmt_as_styled(&self) -> String {
+        owo_colors_impl::fmt_as_styled(self, &StyleSheet::new())
+    }
+    /// Format the error as [`fmt_as_styled`](Self::fmt_as_styled) does, with the given `styles`
+    /// for each [severity level](ErrorType::severity_level), the message and the location.
+    #[cfg(feature = "owo-colors")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "owo-colors")))]
+    fn fmt_as_styled_with(&self, styles: &StyleSheet) -> String {
+        owo_colors_impl::fmt_as_styled(self, styles)
+    }
+
+    /// Assert that the [code](ErrorType::code) of the error equals `expected`.
+    ///
+    /// On mismatch, panics with a character diff between `expected` and the actual code.
+    ///
+    /// ```
+    /// # use core::fmt;
+    /// # use error_enum_core::{vec1, Cow, ErrorType, ErrorTypeExt, IterAdditional, Kind, LabelVec1, SimpleSpan};
+    /// #[derive(Debug)]
+    /// struct AccessDenied;
+    /// # impl fmt::Display for AccessDenied {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str("access denied")
+    /// #     }
+    /// # }
+    /// # impl core::error::Error for AccessDenied {}
+    /// impl ErrorType for AccessDenied {
+    ///     // ...
+    /// #     type Span = SimpleSpan;
+    /// #     type Kind = Kind;
+    /// #     type Message = String;
+    /// #     type Label = String;
+    /// #     fn kind(&self) -> Kind {
+    /// #         Kind::Error
+    /// #     }
+    ///     fn number(&self) -> Cow<'_, str> {
+    ///         Cow::Borrowed("11")
+    ///     }
+    /// #     fn primary_span(&self) -> Option<SimpleSpan> {
+    /// #         None
+    /// #     }
+    /// #     fn primary_message(&self) -> String {
+    /// #         self.to_string()
+    /// #     }
+    /// #     fn primary_labels(&self) -> LabelVec1<SimpleSpan, String> {
+    /// #         vec1![(SimpleSpan::default(), self.to_string())]
+    /// #     }
+    /// #     fn additional(&self) -> IterAdditional<Self> {
+    /// #         Box::new([].into_iter())
+    /// #     }
+    /// }
+    ///
+    /// AccessDenied.assert_code("E11");
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use core::fmt;
+    /// # use error_enum_core::{vec1, Cow, ErrorType, ErrorTypeExt, IterAdditional, Kind, LabelVec1, SimpleSpan};
+    /// # #[derive(Debug)]
+    /// # struct AccessDenied;
+    /// # impl fmt::Display for AccessDenied {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str("access denied")
+    /// #     }
+    /// # }
+    /// # impl core::error::Error for AccessDenied {}
+    /// # impl ErrorType for AccessDenied {
+    /// #     type Span = SimpleSpan;
+    /// #     type Kind = Kind;
+    /// #     type Message = String;
+    /// #     type Label = String;
+    /// #     fn kind(&self) -> Kind {
+    /// #         Kind::Error
+    /// #     }
+    /// #     fn number(&self) -> Cow<'_, str> {
+    /// #         Cow::Borrowed("11")
+    /// #     }
+    /// #     fn primary_span(&self) -> Option<SimpleSpan> {
+    /// #         None
+    /// #     }
+    /// #     fn primary_message(&self) -> String {
+    /// #         self.to_string()
+    /// #     }
+    /// #     fn primary_labels(&self) -> LabelVec1<SimpleSpan, String> {
+    /// #         vec1![(SimpleSpan::default(), self.to_string())]
+    /// #     }
+    /// #     fn additional(&self) -> IterAdditional<Self> {
+    /// #         Box::new([].into_iter())
+    /// #     }
+    /// # }
+    /// AccessDenied.assert_code("E10");
+    /// ```
+    #[cfg(feature = "testing")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+    #[track_caller]
+    fn assert_code(&self, expected: &str) {
+        let code = self.code();
+        assert!(
+            code == expected,
+            "error code mismatch:\n{}",
+            prettydiff::diff_chars(expected, &code),
+        );
+    }
 }
 
 impl<T: ErrorType + ?Sized> ErrorTypeExt for T {}
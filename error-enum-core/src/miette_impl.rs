@@ -1,4 +1,4 @@
-use crate::{AdditionalKind, DiagnosticKind, ErrorType, Indexer, Span};
+use crate::{DiagnosticKind, ErrorType, ErrorTypeExt, Indexer, Span};
 use alloc::{
     boxed::Box,
     string::{String, ToString as _},
@@ -14,6 +14,7 @@ pub(crate) struct Wrapper<'a, T: ?Sized, S>(&'a T, SpanWrapper<S>);
 
 impl<'a, T: ErrorType<Span = S> + ?Sized, S: Span + Default> Wrapper<'a, T, S> {
     pub(crate) fn new(value: &'a T) -> Self {
+        crate::span::debug_assert_in_bounds(value);
         Self(value, SpanWrapper(value.primary_span().unwrap_or_default()))
     }
 }
@@ -26,15 +27,19 @@ impl<T: ErrorType + 'static, S: Span + Send + Sync> Wrapper<'_, T, S> {
 
 impl<T: ErrorType + ?Sized, S> fmt::Debug for Wrapper<'_, T, S> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0.primary_message())
+        write!(f, "{}", self.0.primary_message_verbose())
     }
 }
 impl<T: ErrorType + ?Sized, S> fmt::Display for Wrapper<'_, T, S> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0.primary_message())
+        write!(f, "{}", self.0.primary_message_verbose())
+    }
+}
+impl<T: ErrorType + ?Sized, S> Error for Wrapper<'_, T, S> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.0.source()
     }
 }
-impl<T: ErrorType + ?Sized, S> Error for Wrapper<'_, T, S> {}
 
 fn is_placeholder_span<S: Span>(span: &S) -> bool {
     span.start() == span.end() && span.start() == 0 && span.uri().to_string().is_empty()
@@ -86,24 +91,19 @@ impl<T: ErrorType + ?Sized, S: Span + Send + Sync> Diagnostic for Wrapper<'_, T,
                 ));
             }
         }
+        if let Some((span, text)) = self.0.suggestion() {
+            if !is_placeholder_span(&span) {
+                labeled.push(LabeledSpan::new_with_span(
+                    Some(alloc::format!("help: replace with `{text}`")),
+                    SourceSpan::new(span.start().into(), span.end() - span.start()),
+                ));
+            }
+        }
         Some(Box::new(labeled.into_iter()))
     }
     fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
-        let mut parts = Vec::new();
-        for (message, _labels, kind) in self.0.additional() {
-            if !matches!(kind, AdditionalKind::Help) {
-                continue;
-            }
-            let message = message.to_string();
-            if !message.is_empty() {
-                parts.push(message);
-            }
-        }
-        if parts.is_empty() {
-            None
-        } else {
-            Some(Box::new(parts.join("\n")))
-        }
+        let help = ErrorTypeExt::help(self.0)?;
+        Some(Box::new(help))
     }
 }
 
@@ -126,6 +126,14 @@ impl<S: Span + Send + Sync> SourceCode for SpanWrapper<S> {
         context_lines_before: usize,
         context_lines_after: usize,
     ) -> Result<Box<dyn SpanContents<'a> + 'a>, MietteError> {
+        debug_assert!(
+            span.offset() + span.len() <= self.0.source_len(),
+            "span {}..{} is out of bounds of `{}` ({} bytes)",
+            span.offset(),
+            span.offset() + span.len(),
+            self.0.uri(),
+            self.0.source_len(),
+        );
         let index = self.0.source_index();
         let (start, end) = index.span_with_context_lines(
             span.offset(),
@@ -133,7 +141,8 @@ impl<S: Span + Send + Sync> SourceCode for SpanWrapper<S> {
             context_lines_before,
             context_lines_after,
         );
-        let (start_line, start_column) = index.line_col_at(span.offset());
+        let (start_line, start_column) =
+            index.char_col_at(span.offset(), self.0.source_text().as_ref());
         let (end_line, _) = index.line_col_at(span.offset() + span.len());
         let name = self.0.uri().to_string();
         let data = &self.0.source_text().as_ref().as_bytes()[start..end];
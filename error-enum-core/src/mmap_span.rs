@@ -0,0 +1,151 @@
+use crate::{LineIndexer, Span};
+use alloc::sync::Arc;
+use core::{fmt, ops::Range, str::Utf8Error};
+use memmap2::Mmap;
+
+/// Source text of an [`MmapSpan`]: a memory-mapped file validated as UTF-8.
+///
+/// Cloning shares the mapping and its [`LineIndexer`], and two sources are equal only if they
+/// share them.
+#[derive(Clone, Default)]
+pub struct MmapSource(Option<Arc<Mapped>>);
+
+/// A mapping and the [`LineIndexer`] built over it.
+struct Mapped {
+    mmap: Mmap,
+    indexer: Arc<LineIndexer>,
+}
+
+impl MmapSource {
+    /// Wrap a mapping, checking that it is valid UTF-8.
+    ///
+    /// The whole file is scanned once here to build its [`LineIndexer`], which every
+    /// [`MmapSpan`] over this source shares.
+    ///
+    /// The mapped file must not be modified while the source is alive, see [`Mmap::map`].
+    pub fn new(mmap: Mmap) -> Result<Self, Utf8Error> {
+        let text = core::str::from_utf8(&mmap)?;
+        let indexer = LineIndexer::new(text).into();
+        Ok(Self(Some(Arc::new(Mapped { mmap, indexer }))))
+    }
+
+    /// The shared [`LineIndexer`] of the source.
+    fn indexer(&self) -> Arc<LineIndexer> {
+        match &self.0 {
+            Some(mapped) => mapped.indexer.clone(),
+            None => LineIndexer::new("").into(),
+        }
+    }
+}
+
+impl AsRef<str> for MmapSource {
+    fn as_ref(&self) -> &str {
+        match &self.0 {
+            // SAFETY: the mapping was validated as UTF-8 in `new` and is read-only.
+            Some(mapped) => unsafe { core::str::from_utf8_unchecked(&mapped.mmap) },
+            None => "",
+        }
+    }
+}
+
+impl PartialEq for MmapSource {
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for MmapSource {}
+
+impl fmt::Debug for MmapSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MmapSource")
+            .field(&self.as_ref().len())
+            .finish()
+    }
+}
+
+/// An implementation of [`Span`] over a memory-mapped file, for sources too large to copy.
+///
+/// Like [`SimpleSpan`](crate::SimpleSpan), spans over the same file share the mapping and its
+/// [`LineIndexer`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MmapSpan {
+    uri: Arc<str>,
+    source: MmapSource,
+    indexer: Arc<LineIndexer>,
+    start: usize,
+    end: usize,
+}
+
+impl MmapSpan {
+    /// Create a new [`MmapSpan`].
+    ///
+    /// The span shares the [`LineIndexer`] of `source`, so creating spans does not rescan the
+    /// file.
+    pub fn new(uri: impl Into<Arc<str>>, source: MmapSource, start: usize, end: usize) -> Self {
+        let indexer = source.indexer();
+        Self {
+            uri: uri.into(),
+            source,
+            indexer,
+            start,
+            end,
+        }
+    }
+
+    /// Returns a copy of this span with a different byte range, sharing source identity.
+    pub fn with_range(&self, start: usize, end: usize) -> Self {
+        Self {
+            uri: self.uri.clone(),
+            source: self.source.clone(),
+            indexer: self.indexer.clone(),
+            start,
+            end,
+        }
+    }
+}
+
+impl Span for MmapSpan {
+    type Uri = Arc<str>;
+    type Source = MmapSource;
+    type Index = Arc<LineIndexer>;
+
+    fn start(&self) -> usize {
+        self.start
+    }
+    fn end(&self) -> usize {
+        self.end
+    }
+    fn source_text(&self) -> &Self::Source {
+        &self.source
+    }
+    fn source_index(&self) -> &Self::Index {
+        &self.indexer
+    }
+    fn uri(&self) -> &Self::Uri {
+        &self.uri
+    }
+}
+
+impl Default for MmapSpan {
+    fn default() -> Self {
+        Self::new("", MmapSource::default(), 0, 0)
+    }
+}
+
+impl From<&MmapSpan> for MmapSpan {
+    fn from(value: &MmapSpan) -> Self {
+        value.clone()
+    }
+}
+
+/// Re-span `source` to the byte range, e.g. a token range from a lexer.
+impl From<(&MmapSpan, Range<usize>)> for MmapSpan {
+    fn from((source, range): (&MmapSpan, Range<usize>)) -> Self {
+        source.with_range(range.start, range.end)
+    }
+}
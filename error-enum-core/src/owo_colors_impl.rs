@@ -0,0 +1,91 @@
+use crate::{span, ErrorType, ErrorTypeExt};
+use alloc::string::String;
+use owo_colors::{OwoColorize, Stream, Style};
+
+/// Styles used by [`ErrorTypeExt::fmt_as_styled_with`].
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub struct StyleSheet {
+    /// Style of the kind and code of errors, i.e. [severity level](ErrorType::severity_level) 0.
+    pub error: Style,
+    /// Style of the kind and code of warnings, i.e. severity level 1.
+    pub warning: Style,
+    /// Style of the kind and code of anything less severe than a warning.
+    pub note: Style,
+    /// Style of the primary message.
+    pub message: Style,
+    /// Style of the location of the primary span.
+    pub location: Style,
+}
+
+impl StyleSheet {
+    /// Create the default [`StyleSheet`]: bold red errors, bold yellow warnings, bold cyan
+    /// notes, bold messages and blue locations.
+    pub const fn new() -> Self {
+        Self {
+            error: Style::new().red().bold(),
+            warning: Style::new().yellow().bold(),
+            note: Style::new().cyan().bold(),
+            message: Style::new().bold(),
+            location: Style::new().blue(),
+        }
+    }
+    /// Set [`error`](Self::error).
+    pub const fn with_error(mut self, error: Style) -> Self {
+        self.error = error;
+        self
+    }
+    /// Set [`warning`](Self::warning).
+    pub const fn with_warning(mut self, warning: Style) -> Self {
+        self.warning = warning;
+        self
+    }
+    /// Set [`note`](Self::note).
+    pub const fn with_note(mut self, note: Style) -> Self {
+        self.note = note;
+        self
+    }
+    /// Set [`message`](Self::message).
+    pub const fn with_message(mut self, message: Style) -> Self {
+        self.message = message;
+        self
+    }
+    /// Set [`location`](Self::location).
+    pub const fn with_location(mut self, location: Style) -> Self {
+        self.location = location;
+        self
+    }
+}
+
+impl Default for StyleSheet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub(crate) fn fmt_as_styled<T: ErrorType + ?Sized>(error: &T, styles: &StyleSheet) -> String {
+    let kind_style = match error.severity_level() {
+        0 => styles.error,
+        1 => styles.warning,
+        _ => styles.note,
+    };
+    let tag = crate::header_tag(error, &error.code());
+    let mut out = crate::header(
+        tag.if_supports_color(Stream::Stderr, |text| text.style(kind_style)),
+        error
+            .primary_message()
+            .if_supports_color(Stream::Stderr, |text| text.style(styles.message)),
+    );
+    let location = error
+        .primary_span()
+        .filter(|span| !span::is_placeholder(span))
+        .and(error.primary_location());
+    if let Some((uri, row, col)) = location {
+        let location = alloc::format!("({uri}:{row}:{col})");
+        out += &alloc::format!(
+            " {}",
+            location.if_supports_color(Stream::Stderr, |text| text.style(styles.location)),
+        );
+    }
+    out
+}
@@ -0,0 +1,89 @@
+use crate::{SimpleSpan, Span};
+use alloc::sync::Arc;
+use proc_macro2::LineColumn;
+
+/// A [`Span`] over the tokens of a [`proc_macro2::Span`], for macro authors.
+///
+/// `proc_macro2` only exposes the location of a span, so the URI and the source text of the
+/// file are passed in separately. Locations of spans need the `span-locations` feature of
+/// `proc_macro2`, which this crate's `proc-macro2` feature enables. Inside a procedural macro,
+/// they are only available on nightly; outside, e.g. in tests or build scripts, they work on
+/// stable.
+#[derive(Clone, Debug)]
+pub struct ProcMacroSpan {
+    span: SimpleSpan,
+    token_span: proc_macro2::Span,
+}
+
+impl ProcMacroSpan {
+    /// Create a new [`ProcMacroSpan`] over `token_span` in `source`.
+    pub fn new(
+        uri: impl Into<Arc<str>>,
+        source: impl Into<Arc<str>>,
+        token_span: proc_macro2::Span,
+    ) -> Self {
+        let source = source.into();
+        let start = byte_offset(&source, token_span.start());
+        let end = byte_offset(&source, token_span.end());
+        Self {
+            span: SimpleSpan::new(uri, source, start, end),
+            token_span,
+        }
+    }
+    /// Get the underlying [`proc_macro2::Span`].
+    pub fn token_span(&self) -> proc_macro2::Span {
+        self.token_span
+    }
+}
+
+/// Byte offset of a one-based line and zero-based character column.
+fn byte_offset(source: &str, location: LineColumn) -> usize {
+    let line_start: usize = source
+        .split_inclusive('\n')
+        .take(location.line.saturating_sub(1))
+        .map(str::len)
+        .sum();
+    let rest = &source[line_start..];
+    line_start
+        + rest
+            .char_indices()
+            .nth(location.column)
+            .map_or(rest.len(), |(index, _)| index)
+}
+
+impl Span for ProcMacroSpan {
+    type Uri = <SimpleSpan as Span>::Uri;
+    type Source = <SimpleSpan as Span>::Source;
+    type Index = <SimpleSpan as Span>::Index;
+
+    fn start(&self) -> usize {
+        self.span.start()
+    }
+    fn end(&self) -> usize {
+        self.span.end()
+    }
+    fn source_text(&self) -> &Self::Source {
+        self.span.source_text()
+    }
+    fn source_index(&self) -> &Self::Index {
+        self.span.source_index()
+    }
+    fn uri(&self) -> &Self::Uri {
+        self.span.uri()
+    }
+}
+
+impl Default for ProcMacroSpan {
+    fn default() -> Self {
+        Self {
+            span: SimpleSpan::default(),
+            token_span: proc_macro2::Span::call_site(),
+        }
+    }
+}
+
+impl From<&ProcMacroSpan> for ProcMacroSpan {
+    fn from(value: &ProcMacroSpan) -> Self {
+        value.clone()
+    }
+}
@@ -0,0 +1,254 @@
+use crate::{DiagnosticKind, ErrorType, ErrorTypeExt, IterAdditional, LabelVec1};
+use alloc::{borrow::Cow, collections::BTreeMap, string::String};
+use core::{cell::RefCell, fmt};
+
+/// Options applied when rendering an error, independent of the error itself.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub struct RenderOptions {
+    /// Render warnings as errors (severity and code prefix), see
+    /// [`DiagnosticKind::warning_as_error`]. The code is rebuilt by
+    /// [`ErrorType::code_with_kind`], so codes without a kind prefix are kept as is.
+    pub warnings_as_errors: bool,
+}
+
+impl RenderOptions {
+    /// Create the default [`RenderOptions`].
+    pub const fn new() -> Self {
+        Self {
+            warnings_as_errors: false,
+        }
+    }
+    /// Set [`warnings_as_errors`](Self::warnings_as_errors).
+    pub const fn with_warnings_as_errors(mut self, warnings_as_errors: bool) -> Self {
+        self.warnings_as_errors = warnings_as_errors;
+        self
+    }
+}
+
+/// Output format of [`Diagnostics::write_all`](crate::Diagnostics::write_all).
+///
+/// Each backend variant is available with its feature and uses the backend's default
+/// configuration.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OutputFormat {
+    /// [`ErrorTypeExt::fmt_compact`].
+    #[default]
+    Compact,
+    /// [`ErrorTypeExt::fmt_as_gnu`].
+    Gnu,
+    /// [`ErrorTypeExt::fmt_as_annotate_snippets`].
+    #[cfg(feature = "annotate-snippets")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "annotate-snippets")))]
+    AnnotateSnippets,
+    /// [`ErrorTypeExt::fmt_as_ariadne_report`].
+    #[cfg(feature = "ariadne")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ariadne")))]
+    Ariadne,
+    /// [`ErrorTypeExt::fmt_as_codespan_diagnostic_with`] with the default config and no styles.
+    #[cfg(feature = "codespan-reporting")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "codespan-reporting")))]
+    Codespan,
+    /// [`ErrorTypeExt::fmt_as_rustc_json`].
+    #[cfg(feature = "rustc-json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rustc-json")))]
+    RustcJson,
+    /// [`ErrorTypeExt::fmt_as_html`].
+    #[cfg(feature = "html")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "html")))]
+    Html,
+}
+
+impl OutputFormat {
+    /// Render `error` in this format.
+    #[cfg(feature = "std")]
+    pub(crate) fn render<T: ErrorType>(self, error: &T) -> std::io::Result<String> {
+        Ok(match self {
+            Self::Compact => error.fmt_compact(),
+            Self::Gnu => error.fmt_as_gnu(),
+            #[cfg(feature = "annotate-snippets")]
+            Self::AnnotateSnippets => error.fmt_as_annotate_snippets(),
+            #[cfg(feature = "ariadne")]
+            Self::Ariadne => error.fmt_as_ariadne_report()?,
+            #[cfg(feature = "codespan-reporting")]
+            Self::Codespan => error
+                .fmt_as_codespan_diagnostic_with(Default::default(), None)
+                .map_err(std::io::Error::other)?,
+            #[cfg(feature = "rustc-json")]
+            Self::RustcJson => error.fmt_as_rustc_json().map_err(std::io::Error::other)?,
+            #[cfg(feature = "html")]
+            Self::Html => error.fmt_as_html().map_err(std::io::Error::other)?,
+        })
+    }
+}
+
+/// An error viewed through [`RenderOptions`], see [`ErrorTypeExt::with_render_options`].
+///
+/// It implements [`ErrorType`] itself, so every `fmt_as_*` method of [`ErrorTypeExt`] is
+/// available on it.
+///
+/// [`ErrorTypeExt`]: crate::ErrorTypeExt
+/// [`ErrorTypeExt::with_render_options`]: crate::ErrorTypeExt::with_render_options
+pub struct Rendered<'a, T: ?Sized> {
+    error: &'a T,
+    options: RenderOptions,
+}
+
+impl<'a, T: ?Sized> Rendered<'a, T> {
+    pub(crate) fn new(error: &'a T, options: RenderOptions) -> Self {
+        Self { error, options }
+    }
+    /// Get the wrapped error.
+    pub fn error(&self) -> &'a T {
+        self.error
+    }
+    /// Get the options.
+    pub fn options(&self) -> RenderOptions {
+        self.options
+    }
+}
+
+impl<T: fmt::Debug + ?Sized> fmt::Debug for Rendered<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Rendered")
+            .field("error", &self.error)
+            .field("options", &self.options)
+            .finish()
+    }
+}
+
+impl<T: fmt::Display + ?Sized> fmt::Display for Rendered<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.error, f)
+    }
+}
+
+impl<T: core::error::Error + ?Sized> core::error::Error for Rendered<'_, T> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+impl<T: ErrorType + ?Sized> ErrorType for Rendered<'_, T> {
+    type Span = T::Span;
+    type Kind = T::Kind;
+    type Message = T::Message;
+    type Label = T::Label;
+
+    fn kind(&self) -> Self::Kind {
+        let kind = self.error.kind();
+        if self.options.warnings_as_errors {
+            kind.warning_as_error()
+        } else {
+            kind
+        }
+    }
+    fn number(&self) -> Cow<'_, str> {
+        self.error.number()
+    }
+    fn code(&self) -> Cow<'_, str> {
+        if self.options.warnings_as_errors {
+            self.error.code_with_kind(&self.kind())
+        } else {
+            self.error.code()
+        }
+    }
+    fn code_with_kind(&self, kind: &Self::Kind) -> Cow<'_, str> {
+        self.error.code_with_kind(kind)
+    }
+    fn primary_span(&self) -> Option<Self::Span> {
+        self.error.primary_span()
+    }
+    fn primary_message(&self) -> Self::Message {
+        self.error.primary_message()
+    }
+    fn primary_message_verbose(&self) -> Self::Message {
+        self.error.primary_message_verbose()
+    }
+    fn primary_labels(&self) -> LabelVec1<Self::Span, Self::Label> {
+        self.error.primary_labels()
+    }
+    fn additional(&self) -> IterAdditional<Self> {
+        self.error.additional()
+    }
+    fn severity_level(&self) -> u8 {
+        let level = self.error.severity_level();
+        if self.options.warnings_as_errors && level == 1 {
+            0
+        } else {
+            level
+        }
+    }
+    fn suggestion(&self) -> Option<(Self::Span, String)> {
+        self.error.suggestion()
+    }
+    fn message_template(&self) -> &'static str {
+        self.error.message_template()
+    }
+    fn primary_message_static(&self) -> Option<&'static str> {
+        self.error.primary_message_static()
+    }
+    fn primary_label_template(&self) -> &'static str {
+        self.error.primary_label_template()
+    }
+}
+
+/// An error that memoizes its rendered output, for UIs that render the same error repeatedly.
+///
+/// Each output is keyed by a format name chosen by the caller, e.g. `"ariadne"`, and the
+/// [`RenderOptions`] it was rendered with.
+pub struct CachedRender<T> {
+    error: T,
+    cache: RefCell<BTreeMap<(&'static str, RenderOptions), String>>,
+}
+
+impl<T> CachedRender<T> {
+    /// Wrap `error` with an empty cache.
+    pub const fn new(error: T) -> Self {
+        Self {
+            error,
+            cache: RefCell::new(BTreeMap::new()),
+        }
+    }
+    /// Get the wrapped error.
+    pub fn error(&self) -> &T {
+        &self.error
+    }
+    /// Unwrap the error, dropping the cache.
+    pub fn into_inner(self) -> T {
+        self.error
+    }
+    /// Drop every cached output.
+    pub fn clear(&self) {
+        self.cache.borrow_mut().clear();
+    }
+}
+
+impl<T: ErrorType> CachedRender<T> {
+    /// Get the output cached under `(format, options)`, or call `render` on the error viewed
+    /// through `options` and cache its output.
+    pub fn render_with(
+        &self,
+        format: &'static str,
+        options: RenderOptions,
+        render: impl FnOnce(&Rendered<'_, T>) -> String,
+    ) -> String {
+        if let Some(output) = self.cache.borrow().get(&(format, options)) {
+            return output.clone();
+        }
+        let output = render(&self.error.with_render_options(options));
+        self.cache
+            .borrow_mut()
+            .insert((format, options), output.clone());
+        output
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for CachedRender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachedRender")
+            .field("error", &self.error)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{json::JsonStr, span::is_placeholder, AdditionalKind, DiagnosticKind, ErrorType, Span};
+use alloc::string::{String, ToString};
+use core::fmt::{self, Write};
+
+fn write_span<S: Span>(
+    out: &mut String,
+    span: &S,
+    label: Option<&str>,
+    is_primary: bool,
+) -> fmt::Result {
+    let (line_start, column_start) = span.start_line_col();
+    let (line_end, column_end) = span.end_line_col();
+    write!(
+        out,
+        "{{\"file_name\":{},\"byte_start\":{},\"byte_end\":{},\"line_start\":{line_start},\"line_end\":{line_end},\"column_start\":{column_start},\"column_end\":{column_end},\"is_primary\":{is_primary},\"text\":[],\"label\":",
+        JsonStr(&span.uri().to_string()),
+        span.start(),
+        span.end(),
+    )?;
+    match label {
+        Some(label) => write!(out, "{}", JsonStr(label))?,
+        None => out.write_str("null")?,
+    }
+    out.write_str(
+        ",\"suggested_replacement\":null,\"suggestion_applicability\":null,\"expansion\":null}",
+    )
+}
+
+/// The rustc level of `error`: the kind's own level, unless the severity level is overridden.
+///
+/// rustc has no level for "none", so level `3` maps to `"help"`.
+fn rustc_level<T: ErrorType + ?Sized>(error: &T) -> &'static str {
+    match error.severity_level() {
+        0 => "error",
+        1 => "warning",
+        2 => "note",
+        _ => "help",
+    }
+}
+
+pub(crate) fn fmt_as_rustc_json<T: ErrorType + ?Sized>(error: &T) -> Result<String, fmt::Error> {
+    crate::span::debug_assert_in_bounds(error);
+    let kind = error.kind();
+    let mut out = String::new();
+    write!(
+        out,
+        "{{\"$message_type\":\"diagnostic\",\"message\":{},\"code\":{{\"code\":{},\"explanation\":null}},\"level\":{},\"spans\":[",
+        JsonStr(&error.primary_message().to_string()),
+        JsonStr(&error.code()),
+        JsonStr(if error.severity_level() == kind.severity_level() {
+            kind.rustc_level()
+        } else {
+            rustc_level(error)
+        }),
+    )?;
+    let mut first = true;
+    for (index, (span, label)) in error.primary_labels().iter().enumerate() {
+        if is_placeholder(span) {
+            continue;
+        }
+        if !first {
+            out.write_char(',')?;
+        }
+        first = false;
+        write_span(&mut out, span, Some(&label.to_string()), index == 0)?;
+    }
+    out.write_str("],\"children\":[")?;
+    for (index, (message, labels, kind)) in error.additional().enumerate() {
+        if index > 0 {
+            out.write_char(',')?;
+        }
+        let level = match kind {
+            AdditionalKind::Note => "note",
+            AdditionalKind::Help => "help",
+        };
+        write!(
+            out,
+            "{{\"message\":{},\"code\":null,\"level\":\"{level}\",\"spans\":[",
+            JsonStr(&message.to_string()),
+        )?;
+        let mut first = true;
+        for (span, label) in labels.iter() {
+            if is_placeholder(span) {
+                continue;
+            }
+            if !first {
+                out.write_char(',')?;
+            }
+            first = false;
+            write_span(&mut out, span, Some(&label.to_string()), true)?;
+        }
+        out.write_str("],\"children\":[],\"rendered\":null}")?;
+    }
+    out.write_str("],\"rendered\":null}")?;
+    Ok(out)
+}
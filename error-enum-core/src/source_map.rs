@@ -0,0 +1,44 @@
+use crate::{LineIndexer, SimpleSpan};
+use alloc::{collections::BTreeMap, sync::Arc};
+
+/// A set of source files that hands out [`SimpleSpan`]s sharing one [`LineIndexer`] per file.
+///
+/// [`SimpleSpan::new`] indexes its source on every call. Adding a file here indexes it once, and
+/// every span from [`span`](Self::span) reuses that index.
+#[derive(Clone, Debug, Default)]
+pub struct SourceMap {
+    files: BTreeMap<Arc<str>, (Arc<str>, Arc<LineIndexer>)>,
+}
+
+impl SourceMap {
+    /// Create an empty [`SourceMap`].
+    pub const fn new() -> Self {
+        Self {
+            files: BTreeMap::new(),
+        }
+    }
+    /// Add a source file, replacing any file with the same URI, and get a span of its start.
+    pub fn add(&mut self, uri: impl Into<Arc<str>>, source: impl Into<Arc<str>>) -> SimpleSpan {
+        let uri = uri.into();
+        let source = source.into();
+        let indexer: Arc<LineIndexer> = LineIndexer::new(&source).into();
+        self.files
+            .insert(uri.clone(), (source.clone(), indexer.clone()));
+        SimpleSpan::with_indexer(uri, source, indexer, 0, 0)
+    }
+    /// Get a span of the byte range `start..end` in the file at `uri`, if it has been added.
+    pub fn span(&self, uri: &str, start: usize, end: usize) -> Option<SimpleSpan> {
+        let (uri, (source, indexer)) = self.files.get_key_value(uri)?;
+        Some(SimpleSpan::with_indexer(
+            uri.clone(),
+            source.clone(),
+            indexer.clone(),
+            start,
+            end,
+        ))
+    }
+    /// Get the source text of the file at `uri`, if it has been added.
+    pub fn source(&self, uri: &str) -> Option<&Arc<str>> {
+        self.files.get(uri).map(|(source, _)| source)
+    }
+}
@@ -1,5 +1,11 @@
 use crate::{Indexer, LineIndexer};
-use alloc::sync::Arc;
+use alloc::{
+    boxed::Box,
+    rc::Rc,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
 use core::{fmt, ops::Range};
 
 /// Trait for span types used in error enums.
@@ -21,10 +27,54 @@ pub trait Span: Clone {
     }
     /// Get the source text of the span.
     fn source_text(&self) -> &Self::Source;
+    /// Get the length of the source text in bytes.
+    fn source_len(&self) -> usize {
+        self.source_text().as_ref().len()
+    }
     /// Get the index of the source.
     fn source_index(&self) -> &Self::Index;
     /// Get the URI of the span.
     fn uri(&self) -> &Self::Uri;
+    /// Get the one-based line and one-based character column of the start of the span.
+    fn start_line_col(&self) -> (usize, usize) {
+        line_col(self, self.start())
+    }
+    /// Get the one-based line and one-based character column of the end of the span.
+    fn end_line_col(&self) -> (usize, usize) {
+        line_col(self, self.end())
+    }
+    /// Get the one-based line number and the text of the line the span starts on, without its
+    /// line break, e.g. `(2, "let x = 1;")`.
+    fn numbered_line(&self) -> (usize, &str) {
+        let source = self.source_text().as_ref();
+        let start = self.start().min(source.len());
+        let (line_start, line_end) = self.source_index().line_span_at(start);
+        let text = source
+            .get(line_start.min(start)..line_end.clamp(start, source.len()))
+            .unwrap_or_default()
+            .trim_end_matches(['\n', '\r']);
+        (self.start_line_col().0, text)
+    }
+    /// Get the source text of the lines covered by the span, with `before` and `after` lines of
+    /// context, e.g. for pasting into a bug report.
+    ///
+    /// See [`Indexer::span_with_context_lines`] for how the window is chosen.
+    fn context_snippet(&self, before: usize, after: usize) -> String {
+        let source = self.source_text().as_ref();
+        let (start, end) = context_window(self, before, after);
+        String::from(source.get(start..end).unwrap_or_default())
+    }
+    /// Get the [context snippet](Self::context_snippet) of the span with each line prefixed by
+    /// its one-based line number, e.g. `2 | let x = 1;`.
+    fn context_snippet_with_line_numbers(&self, before: usize, after: usize) -> String {
+        let gutter = gutter_lines(self, before, after);
+        let width = gutter.width;
+        let mut out = String::new();
+        for (index, line) in gutter.lines.iter().enumerate() {
+            out += &alloc::format!("{:>width$} | {line}", gutter.first + index);
+        }
+        out
+    }
     /// Check if the source text of the span is shared with another span.
     ///
     /// # Note
@@ -37,27 +87,371 @@ pub trait Span: Clone {
     }
 }
 
-/// A simple implementation of [`Span`].
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct SimpleSpan {
-    uri: Arc<str>,
-    source: Arc<str>,
-    indexer: Arc<LineIndexer>,
+/// Byte range of the lines around `span`, clamped to its source text.
+fn context_window<S: Span>(span: &S, before: usize, after: usize) -> (usize, usize) {
+    let len = span.source_len();
+    let (start, end) = span.source_index().span_with_context_lines(
+        span.start().min(len),
+        span.end().min(len),
+        before,
+        after,
+    );
+    (start.min(len), end.min(len))
+}
+
+/// The lines around a span, numbered for a line-number gutter.
+struct GutterLines<'a> {
+    /// Byte offset of the first line.
+    start: usize,
+    /// One-based number of the first line.
+    first: usize,
+    /// Width of the largest line number.
+    width: usize,
+    /// The lines, each with its line break.
+    lines: Vec<&'a str>,
+}
+
+/// The lines of the [context window](context_window) of `span`, numbered for a gutter.
+fn gutter_lines<S: Span>(span: &S, before: usize, after: usize) -> GutterLines<'_> {
+    let source = span.source_text().as_ref();
+    let (start, end) = context_window(span, before, after);
+    let snippet = source.get(start..end).unwrap_or_default();
+    let first = span.source_index().line_col_at(start).0 + 1;
+    let lines: Vec<&str> = snippet.split_inclusive('\n').collect();
+    let width = (first + lines.len().saturating_sub(1)).to_string().len();
+    GutterLines {
+        start,
+        first,
+        width,
+        lines,
+    }
+}
+
+/// Width of the text from `from` to `to` on one line of `span`'s source: its terminal width
+/// through [`Indexer::display_col_at`] with the `unicode-width` feature, else its number of
+/// characters.
+fn width_between<S: Span>(span: &S, from: usize, to: usize) -> usize {
+    let source = span.source_text().as_ref();
+    #[cfg(feature = "unicode-width")]
+    {
+        let index = span.source_index();
+        let (_, from) = index.display_col_at(from, source);
+        let (_, to) = index.display_col_at(to, source);
+        to.saturating_sub(from)
+    }
+    #[cfg(not(feature = "unicode-width"))]
+    source.get(from..to).map_or(0, |text| text.chars().count())
+}
+
+/// Render the location of `span` and the lines around it behind a line-number gutter, with a
+/// caret line under the start line of the span, see [`ErrorTypeExt::fmt_as_plain_with_gutter`](crate::ErrorTypeExt::fmt_as_plain_with_gutter).
+///
+/// Tabs before the span are kept in the caret line, so the carets line up however wide the
+/// terminal renders them.
+pub(crate) fn gutter_snippet<S: Span>(
+    span: &S,
+    label: &str,
+    before: usize,
+    after: usize,
+) -> String {
+    let source = span.source_text().as_ref();
+    let GutterLines {
+        start,
+        first,
+        width,
+        lines,
+    } = gutter_lines(span, before, after);
+    let span_start = span.start().min(source.len());
+    let (line, col) = span.start_line_col();
+    let mut out = alloc::format!("{:width$}--> {}:{line}:{col}\n", "", span.uri());
+    out += &alloc::format!("{:width$} |\n", "");
+    let mut pos = start;
+    for (index, line) in lines.iter().enumerate() {
+        let text = line.trim_end_matches(['\n', '\r']);
+        out += &alloc::format!("{:>width$} | {text}\n", first + index);
+        let last = index + 1 == lines.len();
+        if pos <= span_start && (span_start < pos + line.len() || last) {
+            let col = (span_start - pos).min(text.len());
+            let mark_end = span.end().clamp(span_start, pos + text.len()) - pos;
+            let mut padding = String::new();
+            let mut from = pos;
+            for (tab, _) in text.get(..col).unwrap_or_default().match_indices('\t') {
+                padding += &" ".repeat(width_between(span, from, pos + tab));
+                padding.push('\t');
+                from = pos + tab + 1;
+            }
+            padding += &" ".repeat(width_between(span, from, pos + col));
+            let carets = width_between(span, pos + col, pos + mark_end).max(1);
+            out += &alloc::format!("{:width$} | {padding}{}", "", "^".repeat(carets));
+            if !label.is_empty() {
+                out += &alloc::format!(" {label}");
+            }
+            out.push('\n');
+        }
+        pos += line.len();
+    }
+    out
+}
+
+/// Check if the span is a placeholder, i.e. an empty span at `0` with an empty URI.
+pub(crate) fn is_placeholder<S: Span>(span: &S) -> bool {
+    span.start() == span.end() && span.start() == 0 && span.uri().to_string().is_empty()
+}
+
+/// Assert in debug builds that every span of `error` lies within its source text.
+///
+/// A span ending at the end of the source, e.g. for an unexpected end of file, is in bounds.
+#[track_caller]
+pub(crate) fn debug_assert_in_bounds<T: crate::ErrorType + ?Sized>(error: &T) {
+    if !cfg!(debug_assertions) {
+        return;
+    }
+    let primary = error.primary_labels().into_iter().map(|(span, _)| span);
+    let additional = error
+        .additional()
+        .flat_map(|(_, labels, _)| labels.into_iter().map(|(span, _)| span));
+    let suggestion = error.suggestion().map(|(span, _)| span);
+    for span in primary.chain(additional).chain(suggestion) {
+        debug_assert!(
+            span.start() <= span.end() && span.end() <= span.source_len(),
+            "span {}..{} is out of bounds of `{}` ({} bytes)",
+            span.start(),
+            span.end(),
+            span.uri(),
+            span.source_len(),
+        );
+    }
+}
+
+/// One-based line and one-based character column of the byte position `pos` in `span`'s source.
+fn line_col<S: Span>(span: &S, pos: usize) -> (usize, usize) {
+    let (line, col) = span
+        .source_index()
+        .char_col_at(pos, span.source_text().as_ref());
+    (line + 1, col + 1)
+}
+
+/// Byte offset of the zero-based `line` and byte column `col`, clamped to the source text.
+///
+/// Lines past the end clamp to the last line, and columns past the end of a line clamp to the
+/// end of that line, before its line break.
+fn offset_at(indexer: &LineIndexer, source: &str, (line, col): (usize, usize)) -> usize {
+    let ends = indexer.as_slice();
+    let line = line.min(ends.len() - 1);
+    let start = line.checked_sub(1).map_or(0, |prev| ends[prev]);
+    let text = &source[start..ends[line]];
+    let text = text.strip_suffix('\n').unwrap_or(text);
+    let text = text.strip_suffix('\r').unwrap_or(text);
+    start + col.min(text.len())
+}
+
+macro_rules! shared_span {
+    ($(#[$meta:meta])* $name:ident, $ptr:ident) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub struct $name {
+            uri: $ptr<str>,
+            source: $ptr<str>,
+            indexer: $ptr<LineIndexer>,
+            start: usize,
+            end: usize,
+        }
+
+        impl $name {
+            #[doc = concat!("Create a new [`", stringify!($name), "`].")]
+            pub fn new(
+                uri: impl Into<$ptr<str>>,
+                source: impl Into<$ptr<str>>,
+                start: usize,
+                end: usize,
+            ) -> Self {
+                let uri = uri.into();
+                let source = source.into();
+                let indexer = LineIndexer::new(&source).into();
+                Self {
+                    uri,
+                    source,
+                    indexer,
+                    start,
+                    end,
+                }
+            }
+
+            #[doc = concat!("Create a new [`", stringify!($name), "`] with a prebuilt [`LineIndexer`].")]
+            ///
+            /// `indexer` must be built from `source`. Sharing one indexer between spans over the
+            /// same source avoids rebuilding it for every span.
+            pub fn with_indexer(
+                uri: impl Into<$ptr<str>>,
+                source: impl Into<$ptr<str>>,
+                indexer: $ptr<LineIndexer>,
+                start: usize,
+                end: usize,
+            ) -> Self {
+                let source = source.into();
+                debug_assert_eq!(
+                    indexer.as_slice().last(),
+                    Some(&source.len()),
+                    "indexer must be built from the source",
+                );
+                Self {
+                    uri: uri.into(),
+                    source,
+                    indexer,
+                    start,
+                    end,
+                }
+            }
+
+            #[doc = concat!("Create a new [`", stringify!($name), "`] from zero-based `(line, column)` pairs.")]
+            ///
+            /// Columns are byte offsets within the line, as returned by [`Indexer::line_col_at`].
+            /// Out-of-range lines and columns are clamped to the source text.
+            pub fn from_line_col(
+                uri: impl Into<$ptr<str>>,
+                source: impl Into<$ptr<str>>,
+                start: (usize, usize),
+                end: (usize, usize),
+            ) -> Self {
+                let span = Self::new(uri, source, 0, 0);
+                let start = offset_at(&span.indexer, &span.source, start);
+                let end = offset_at(&span.indexer, &span.source, end);
+                span.with_range(start, end.max(start))
+            }
+
+            /// Returns a copy of this span with a different byte range, sharing source identity.
+            pub fn with_range(&self, start: usize, end: usize) -> Self {
+                Self {
+                    uri: self.uri.clone(),
+                    source: self.source.clone(),
+                    indexer: self.indexer.clone(),
+                    start,
+                    end,
+                }
+            }
+
+            /// Returns a copy of this span with a different URI, keeping the source text and range.
+            ///
+            /// Useful when the same source is reported under another name, e.g. a relocated file.
+            pub fn with_uri(&self, uri: impl Into<$ptr<str>>) -> Self {
+                Self {
+                    uri: uri.into(),
+                    source: self.source.clone(),
+                    indexer: self.indexer.clone(),
+                    start: self.start,
+                    end: self.end,
+                }
+            }
+        }
+
+        impl Span for $name {
+            type Uri = $ptr<str>;
+            type Source = $ptr<str>;
+            type Index = $ptr<LineIndexer>;
+
+            fn start(&self) -> usize {
+                self.start
+            }
+            fn end(&self) -> usize {
+                self.end
+            }
+            fn source_text(&self) -> &Self::Source {
+                &self.source
+            }
+            fn source_index(&self) -> &Self::Index {
+                &self.indexer
+            }
+            fn uri(&self) -> &Self::Uri {
+                &self.uri
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self::new("", "", 0, 0)
+            }
+        }
+
+        impl From<&$name> for $name {
+            fn from(value: &$name) -> Self {
+                value.clone()
+            }
+        }
+
+        /// Re-span `source` to the byte range, e.g. a token range from a lexer.
+        impl From<(&$name, Range<usize>)> for $name {
+            fn from((source, range): (&$name, Range<usize>)) -> Self {
+                source.with_range(range.start, range.end)
+            }
+        }
+
+        /// Re-span `source` to the byte range, e.g. a token range from a lexer.
+        impl From<(&$name, &Range<usize>)> for $name {
+            fn from((source, range): (&$name, &Range<usize>)) -> Self {
+                source.with_range(range.start, range.end)
+            }
+        }
+
+        /// Re-span `source` to the `(start, end)` byte offsets, e.g. a token from a lexer.
+        impl From<(&$name, (usize, usize))> for $name {
+            fn from((source, (start, end)): (&$name, (usize, usize))) -> Self {
+                source.with_range(start, end)
+            }
+        }
+
+        /// Re-span `source` to the `(start, end)` byte offsets, e.g. a token from a lexer.
+        impl From<(&$name, &(usize, usize))> for $name {
+            fn from((source, &(start, end)): (&$name, &(usize, usize))) -> Self {
+                source.with_range(start, end)
+            }
+        }
+    };
+}
+
+shared_span!(
+    /// A simple implementation of [`Span`].
+    SimpleSpan,
+    Arc
+);
+
+shared_span!(
+    /// A single-threaded [`SimpleSpan`], sharing its source through [`Rc`] instead of [`Arc`].
+    ///
+    /// It is neither `Send` nor `Sync`, so it cannot be used with backends that require them,
+    /// such as miette.
+    ///
+    /// ```compile_fail
+    /// fn assert_send<T: Send>() {}
+    /// assert_send::<error_enum_core::SimpleSpanRc>();
+    /// ```
+    SimpleSpanRc,
+    Rc
+);
+
+/// An implementation of [`Span`] that owns its URI and source text.
+///
+/// Unlike [`SimpleSpan`], nothing is shared through [`Arc`], so cloning copies the source text.
+/// Prefer it for throwaway errors that are rendered once.
+#[derive(Debug, PartialEq, Eq)]
+pub struct OwnedSpan {
+    uri: String,
+    source: String,
+    indexer: Box<LineIndexer>,
     start: usize,
     end: usize,
 }
 
-impl SimpleSpan {
-    /// Create a new [`SimpleSpan`].
+impl OwnedSpan {
+    /// Create a new [`OwnedSpan`].
     pub fn new(
-        uri: impl Into<Arc<str>>,
-        source: impl Into<Arc<str>>,
+        uri: impl Into<String>,
+        source: impl Into<String>,
         start: usize,
         end: usize,
     ) -> Self {
         let uri = uri.into();
         let source = source.into();
-        let indexer = LineIndexer::new(&source).into();
+        let indexer = LineIndexer::new(&source);
         Self {
             uri,
             source,
@@ -66,23 +460,24 @@ impl SimpleSpan {
             end,
         }
     }
+}
 
-    /// Returns a copy of this span with a different byte range, sharing source identity.
-    pub fn with_range(&self, start: usize, end: usize) -> Self {
+impl Clone for OwnedSpan {
+    fn clone(&self) -> Self {
         Self {
             uri: self.uri.clone(),
             source: self.source.clone(),
-            indexer: self.indexer.clone(),
-            start,
-            end,
+            indexer: LineIndexer::from_boxed_slice(self.indexer.as_slice().into()),
+            start: self.start,
+            end: self.end,
         }
     }
 }
 
-impl Span for SimpleSpan {
-    type Uri = Arc<str>;
-    type Source = Arc<str>;
-    type Index = Arc<LineIndexer>;
+impl Span for OwnedSpan {
+    type Uri = String;
+    type Source = String;
+    type Index = LineIndexer;
 
     fn start(&self) -> usize {
         self.start
@@ -101,14 +496,14 @@ impl Span for SimpleSpan {
     }
 }
 
-impl Default for SimpleSpan {
+impl Default for OwnedSpan {
     fn default() -> Self {
         Self::new("", "", 0, 0)
     }
 }
 
-impl From<&SimpleSpan> for SimpleSpan {
-    fn from(value: &SimpleSpan) -> Self {
+impl From<&OwnedSpan> for OwnedSpan {
+    fn from(value: &OwnedSpan) -> Self {
         value.clone()
     }
 }
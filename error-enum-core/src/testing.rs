@@ -0,0 +1,86 @@
+//! Assertions for the tests of downstream crates, behind the `testing` feature.
+
+use crate::ErrorType;
+#[cfg(feature = "std")]
+use crate::OutputFormat;
+#[cfg(feature = "std")]
+use alloc::format;
+
+/// Assert that the given codes are distinct, each being a letter prefix, such as its kind's
+/// [prefix](crate::DiagnosticKind::code_prefix), followed by digits.
+///
+/// Pass `ALL_CODES` generated by the derive / `error_type!` macros, e.g.
+/// `assert_codes(MyError::ALL_CODES)`, so that a downstream test catches duplicated or
+/// malformed codes of every variant. Use [`assert_codes_with`] for a custom code format.
+///
+/// # Panics
+///
+/// Panics if two codes are equal or a code is malformed.
+#[track_caller]
+pub fn assert_codes(codes: &[&str]) {
+    assert_codes_with(codes, |code| {
+        let number = code.trim_start_matches(|c: char| c.is_ascii_alphabetic());
+        number.len() < code.len()
+            && !number.is_empty()
+            && number.bytes().all(|b| b.is_ascii_digit())
+    });
+}
+
+/// Assert that the given codes are distinct, each accepted by `is_valid`.
+///
+/// See [`assert_codes`].
+///
+/// # Panics
+///
+/// Panics if two codes are equal or `is_valid` rejects a code.
+#[track_caller]
+pub fn assert_codes_with(codes: &[&str], is_valid: impl Fn(&str) -> bool) {
+    let mut seen = alloc::collections::BTreeSet::new();
+    for &code in codes {
+        assert!(is_valid(code), "malformed error code {code:?}");
+        assert!(seen.insert(code), "duplicated error code {code:?}");
+    }
+}
+
+/// Assert that no code of `A` is also a code of `B`.
+///
+/// Codes are listed by [`ErrorType::all_codes`], so nested variants are expanded and the
+/// `code_base` is included. Call it for two error types reported in one stream, e.g.
+/// `assert_disjoint_codes::<ParseError, IoError>()`, so that a downstream test catches clashing
+/// code namespaces.
+///
+/// # Panics
+///
+/// Panics if a code is in both `A` and `B`.
+#[track_caller]
+pub fn assert_disjoint_codes<A: ErrorType, B: ErrorType>() {
+    let codes: alloc::collections::BTreeSet<_> = A::all_codes().into_iter().collect();
+    for code in B::all_codes() {
+        assert!(
+            !codes.contains(&code),
+            "error code {code:?} is in both code sets",
+        );
+    }
+}
+
+/// Assert that `error` renders in `format` exactly as `expected`, showing a line diff otherwise.
+///
+/// Render options are the defaults; wrap the error with
+/// [`with_render_options`](crate::ErrorTypeExt::with_render_options) to test others.
+///
+/// # Panics
+///
+/// Panics if the output differs from `expected`, or if rendering fails.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[track_caller]
+pub fn assert_renders<T: ErrorType>(error: &T, format: OutputFormat, expected: &str) {
+    let actual = format
+        .render(error)
+        .unwrap_or_else(|err| format!("<failed to render: {err}>"));
+    assert!(
+        actual == expected,
+        "rendered output mismatch:\n{}\n---------- actual ----------\n{actual}",
+        prettydiff::diff_lines(expected, &actual),
+    );
+}
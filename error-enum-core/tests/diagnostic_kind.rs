@@ -6,6 +6,34 @@ use error_enum_core::{DiagnosticKind, Kind};
 fn builtin_code_prefix() {
     assert_eq!(Kind::Error.code_prefix(), "E");
     assert_eq!(Kind::Warn.code_prefix(), "W");
+    assert_eq!(Kind::Info.code_prefix(), "I");
+    assert_eq!(Kind::Note.code_prefix(), "N");
+    assert_eq!(Kind::Help.code_prefix(), "H");
+}
+
+#[test]
+fn builtin_severity_level() {
+    let levels: Vec<_> = Kind::ALL.iter().map(Kind::severity_level).collect();
+    assert_eq!(levels, [0, 1, 2, 2, 2]);
+    assert_eq!(Kind::Warn.warning_as_error(), Kind::Error);
+    assert_eq!(Kind::Note.warning_as_error(), Kind::Note);
+}
+
+#[test]
+fn all_kinds() {
+    // Exhaustive, so that a new variant fails to compile until it is placed in `Kind::ALL`.
+    let position = |kind: Kind| match kind {
+        Kind::Error => 0,
+        Kind::Warn => 1,
+        Kind::Info => 2,
+        Kind::Note => 3,
+        Kind::Help => 4,
+    };
+    assert_eq!(Kind::ALL.len(), 5);
+    for (index, kind) in Kind::ALL.iter().enumerate() {
+        assert_eq!(position(*kind), index);
+    }
+    assert!(Kind::ALL.is_sorted());
 }
 
 #[test]
@@ -20,6 +48,18 @@ fn builtin_annotate_snippets() {
         Kind::Warn.as_annotate_snippets(),
         AnnotationType::Warning
     ));
+    assert!(matches!(
+        Kind::Info.as_annotate_snippets(),
+        AnnotationType::Info
+    ));
+    assert!(matches!(
+        Kind::Note.as_annotate_snippets(),
+        AnnotationType::Note
+    ));
+    assert!(matches!(
+        Kind::Help.as_annotate_snippets(),
+        AnnotationType::Help
+    ));
 }
 
 #[test]
@@ -28,6 +68,9 @@ fn builtin_codespan() {
     use codespan_reporting::diagnostic::Severity;
     assert!(matches!(Kind::Error.as_codespan(), Severity::Error));
     assert!(matches!(Kind::Warn.as_codespan(), Severity::Warning));
+    assert!(matches!(Kind::Info.as_codespan(), Severity::Note));
+    assert!(matches!(Kind::Note.as_codespan(), Severity::Note));
+    assert!(matches!(Kind::Help.as_codespan(), Severity::Help));
 }
 
 #[test]
@@ -35,4 +78,5 @@ fn builtin_codespan() {
 fn builtin_miette() {
     assert!(matches!(Kind::Error.as_miette(), miette::Severity::Error));
     assert!(matches!(Kind::Warn.as_miette(), miette::Severity::Warning));
+    assert!(matches!(Kind::Help.as_miette(), miette::Severity::Advice));
 }
@@ -35,3 +35,118 @@ fn line_indexer_works() {
     assert_eq!(indexer.span_with_context_lines(22, 26, 1, 1), (6, 27)); // 'World\nThis is a test.'
     assert_eq!(indexer.span_with_context_lines(22, 26, 2, 2), (0, 27)); // entire text
 }
+
+#[test]
+fn lines() {
+    use error_enum_core::LineIndexer;
+
+    /// An indexer relying on the default implementation of [`Indexer::lines`].
+    struct Walking(Box<LineIndexer>);
+    impl Indexer for Walking {
+        fn line_col_at(&self, pos: usize) -> (usize, usize) {
+            self.0.line_col_at(pos)
+        }
+        fn line_span_at(&self, pos: usize) -> (usize, usize) {
+            self.0.line_span_at(pos)
+        }
+        fn span_with_context_lines(
+            &self,
+            start: usize,
+            end: usize,
+            context_lines_before: usize,
+            context_lines_after: usize,
+        ) -> (usize, usize) {
+            self.0
+                .span_with_context_lines(start, end, context_lines_before, context_lines_after)
+        }
+    }
+
+    for (text, lines) in [
+        (
+            "Hello\nWorld\nThis is a test.",
+            &[(0, 6), (6, 12), (12, 27)][..],
+        ),
+        ("Hello\n\nWorld\n", &[(0, 6), (6, 7), (7, 13)]),
+        ("", &[]),
+    ] {
+        let indexer = LineIndexer::new(text);
+        assert_eq!(indexer.lines().collect::<Vec<_>>(), lines, "{text:?}");
+        let indexer = Walking(indexer);
+        assert_eq!(indexer.lines().collect::<Vec<_>>(), lines, "{text:?}");
+    }
+}
+
+#[test]
+fn char_columns() {
+    use error_enum_core::LineIndexer;
+    let text = "ok\nlet 名前 = 1;";
+    let indexer = LineIndexer::new(text);
+    let pos = 14; // '='
+
+    assert_eq!(indexer.line_col_at(pos), (1, 11)); // bytes: 4 + 3 + 3 + 1
+    assert_eq!(indexer.char_col_at(pos, text), (1, 7));
+    assert_eq!(indexer.char_col_at(1, text), (0, 1));
+}
+
+#[test]
+fn utf16_columns() {
+    use error_enum_core::LineIndexer;
+    let text = "ok\nlet 🦀é = 1;";
+    let indexer = LineIndexer::new(text);
+    let pos = 14; // '='
+
+    assert_eq!(indexer.line_col_at(pos), (1, 11)); // bytes: 4 + 4 + 2 + 1
+    assert_eq!(text[3..pos].chars().count(), 7);
+    assert_eq!(indexer.utf16_col_at(pos, text), (1, 8)); // '🦀' is a surrogate pair
+    assert_eq!(indexer.utf16_col_at(1, text), (0, 1));
+}
+
+#[test]
+#[cfg(feature = "unicode-width")]
+fn display_columns() {
+    use error_enum_core::LineIndexer;
+    let text = "ok\nlet 名前 = \"e\u{301}\";";
+    let indexer = LineIndexer::new(text);
+    let name = 7; // '名'
+    let eq = 14; // '='
+    let quote = 20; // closing '"'
+
+    assert_eq!(indexer.display_col_at(name, text), (1, 4));
+    assert_eq!(indexer.display_col_at(eq, text), (1, 9)); // each glyph is two columns wide
+    assert_eq!(indexer.display_col_at(quote, text), (1, 13)); // U+0301 takes no column
+}
+
+#[test]
+fn remap_indexer() {
+    use error_enum_core::{LineIndexer, RemapIndexer};
+    let original = "ab/*x*/cd\nef";
+    // Strip the comment, then insert `ZZ` before `ef`: "abcd\nZZef".
+    let indexer = RemapIndexer::new(LineIndexer::new(original), [(2, 5), (5, -2)]);
+
+    assert_eq!(indexer.original_offset(0), 0); // 'a'
+    assert_eq!(indexer.original_offset(2), 7); // 'c'
+    assert_eq!(indexer.original_offset(4), 9); // '\n'
+    assert_eq!(indexer.original_offset(5), 10); // 'Z', inserted
+    assert_eq!(indexer.original_offset(6), 10); // 'Z', inserted
+    assert_eq!(indexer.original_offset(7), 10); // 'e'
+    assert_eq!(indexer.original_offset(8), 11); // 'f'
+
+    assert_eq!(indexer.line_col_at(1), (0, 1)); // 'b'
+    assert_eq!(indexer.line_col_at(3), (0, 8)); // 'd'
+    assert_eq!(indexer.line_col_at(6), (1, 0)); // 'Z'
+    assert_eq!(indexer.line_col_at(8), (1, 1)); // 'f'
+
+    assert_eq!(indexer.line_span_at(3), (0, 10)); // 'ab/*x*/cd\n'
+    assert_eq!(indexer.span_with_context_lines(2, 8, 0, 0), (0, 12)); // entire text
+    assert_eq!(indexer.utf16_col_at(3, original), (0, 8));
+    assert_eq!(indexer.char_col_at(3, original), (0, 8));
+}
+
+#[test]
+fn remap_indexer_without_edits() {
+    use error_enum_core::{LineIndexer, RemapIndexer};
+    let text = "Hello\nWorld";
+    let indexer = RemapIndexer::new(LineIndexer::new(text), []);
+    assert_eq!(indexer.line_col_at(7), (1, 1));
+    assert_eq!(indexer.base().line_col_at(7), (1, 1));
+}
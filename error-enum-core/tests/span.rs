@@ -0,0 +1,113 @@
+//! Tests for the [`Span`] implementations.
+
+#![allow(clippy::unwrap_used)]
+
+use error_enum_core::{SimpleSpan, Span};
+
+#[test]
+fn simple_span_with_uri() {
+    let span = SimpleSpan::new("a.rs", "let x = 1;", 4, 5);
+    let relocated = span.with_uri("b.rs");
+    assert_eq!(span.uri().as_ref(), "a.rs");
+    assert_eq!(relocated.uri().as_ref(), "b.rs");
+    assert_eq!(relocated.range(), span.range());
+    assert_eq!(relocated.source_text(), span.source_text());
+    assert!(!relocated.share_source_text(&span));
+}
+
+#[test]
+fn simple_span_rc() {
+    use error_enum_core::SimpleSpanRc;
+
+    let span = SimpleSpanRc::new("a.rs", "let x = 1;", 4, 5);
+    let other = span.with_range(8, 9);
+    assert_eq!(span.source_text().as_ptr(), other.source_text().as_ptr());
+    assert!(other.share_source_text(&span));
+    assert_eq!(&other.source_text()[other.range()], "1");
+    assert_eq!(SimpleSpanRc::from((&span, 0..3)).range(), 0..3);
+}
+
+#[test]
+fn simple_span_from_line_col() {
+    let source = "fn main() {\r\n    let x = 1;\n}";
+    let span = SimpleSpan::from_line_col("a.rs", source, (1, 8), (1, 9));
+    assert_eq!(span.start(), 21);
+    assert_eq!(span.end(), 22);
+    assert_eq!(&span.source_text()[span.range()], "x");
+
+    let span = SimpleSpan::from_line_col("a.rs", source, (0, 3), (0, 100));
+    assert_eq!(span.range(), 3..11);
+
+    let span = SimpleSpan::from_line_col("a.rs", source, (2, 0), (9, 9));
+    assert_eq!(span.range(), 28..29);
+}
+
+#[test]
+fn source_map_shares_indexer() {
+    use error_enum_core::SourceMap;
+
+    let mut files = SourceMap::new();
+    let file = files.add("a.rs", "let x = 1;\nlet y = x;");
+    files.add("b.rs", "fn main() {}");
+
+    let spans: Vec<_> = (0..1000)
+        .map(|i| files.span("a.rs", i % 10, i % 10 + 1).unwrap())
+        .collect();
+    assert!(spans
+        .iter()
+        .all(|span| core::ptr::eq(&**span.source_index(), &**file.source_index())));
+    assert!(spans.iter().all(|span| span.share_source_text(&file)));
+    assert_eq!(&spans[4].source_text()[spans[4].range()], "x");
+
+    let span = files.span("b.rs", 3, 7).unwrap();
+    assert_eq!(&span.source_text()[span.range()], "main");
+    assert!(files.span("c.rs", 0, 0).is_none());
+}
+
+#[test]
+fn one_based_line_col() {
+    let source = "fn main() {\n    let é = 1;\n}";
+    let span = SimpleSpan::new("main.rs", source, 20, 24);
+    assert_eq!(&source[span.range()], "é =");
+    assert_eq!(span.start_line_col(), (2, 9));
+    assert_eq!(span.end_line_col(), (2, 12));
+
+    let span = SimpleSpan::new("main.rs", source, 0, source.len() - 1);
+    assert_eq!(span.start_line_col(), (1, 1));
+    assert_eq!(span.end_line_col(), (3, 1));
+}
+
+#[test]
+fn context_snippet() {
+    let source = "fn main() {\n    let x = 1;\n    let y = x;\n    dbg!(y);\n}";
+    let start = source.find("= x").unwrap() + 2;
+    let span = SimpleSpan::new("a.rs", source, start, start + 1);
+
+    assert_eq!(span.context_snippet(0, 0), "    let y = x;\n");
+    assert_eq!(
+        span.context_snippet(1, 1),
+        "    let x = 1;\n    let y = x;\n    dbg!(y);\n"
+    );
+    assert_eq!(
+        span.context_snippet_with_line_numbers(1, 1),
+        "2 |     let x = 1;\n3 |     let y = x;\n4 |     dbg!(y);\n"
+    );
+    assert_eq!(
+        span.context_snippet_with_line_numbers(9, 9),
+        "1 | fn main() {\n2 |     let x = 1;\n3 |     let y = x;\n4 |     dbg!(y);\n5 | }"
+    );
+}
+
+#[test]
+fn numbered_line() {
+    // The source of `examples/python.rs`.
+    const SOURCE: &str = "print(1 + 2)\nprint(1 + '1')\nprint('1' + '1')";
+    let span = SimpleSpan::new("file://test.py", SOURCE, 23, 26);
+    assert_eq!(span.numbered_line(), (2, "print(1 + '1')"));
+    let span = SimpleSpan::new("file://test.py", SOURCE, 0, 5);
+    assert_eq!(span.numbered_line(), (1, "print(1 + 2)"));
+    let span = SimpleSpan::new("file://test.py", SOURCE, 30, 33);
+    assert_eq!(span.numbered_line(), (3, "print('1' + '1')"));
+    let span = SimpleSpan::new("file://test.py", "a\r\nb", 0, 1);
+    assert_eq!(span.numbered_line(), (1, "a"));
+}
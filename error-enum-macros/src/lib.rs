@@ -19,8 +19,8 @@ use syn::{
     parse_macro_input, parse_quote,
     punctuated::{self, Punctuated},
     token::{self, Brace},
-    Attribute, DeriveInput, Error, Expr, Fields, Generics, Ident, LitStr, Result, Token, Type,
-    Variant, Visibility,
+    Attribute, DeriveInput, Error, Expr, Fields, Generics, Ident, LitInt, LitStr, Result, Token,
+    Type, Variant, Visibility,
 };
 
 extern crate alloc;
@@ -28,6 +28,38 @@ extern crate alloc;
 #[cfg(test)]
 mod tests;
 
+/// A positional `{index}` or `{_index}` argument in a message, preceded by any escaped braces.
+///
+/// Both forms refer to the tuple field bound as `_index`.
+static UNNAMED_ARG: Lazy<Regex> =
+    lazy_regex!(r#"(?<prefix>(^|[^\{])(\{\{)*)\{_?(?<index>\d+)(?<optional>:[^\{\}]*)?\}"#);
+
+/// Collect results, combining every error with [`Error::combine`] instead of stopping at the first.
+///
+/// This reports all invalid variants at once rather than one per compile.
+trait CollectErrors<T>: Iterator<Item = Result<T>> + Sized {
+    fn collect_errors<C: FromIterator<T>>(self) -> Result<C> {
+        let mut error: Option<Error> = None;
+        let collected = self
+            .filter_map(|result| {
+                result
+                    .map_err(|err| match &mut error {
+                        Some(error) => error.combine(err),
+                        None => error = Some(err),
+                    })
+                    .ok()
+            })
+            .collect();
+        error.map_or(Ok(collected), Err)
+    }
+}
+
+impl<T, I: Iterator<Item = Result<T>>> CollectErrors<T> for I {}
+
+/// A `{code}` placeholder in a message, preceded by any escaped braces.
+static CODE_ARG: Lazy<Regex> = lazy_regex!(r#"(?<prefix>(^|[^\{])(\{\{)*)\{code\}"#);
+static DOC_ARG: Lazy<Regex> = lazy_regex!(r#"(?<prefix>(^|[^\{])(\{\{)*)\{doc\}"#);
+
 /// A tuple type with 4 identical types.
 ///
 /// For `impl_error_enum_branch` and `impl_error_enum`,
@@ -87,6 +119,12 @@ impl Parse for ErrorTree {
 
         if input.peek(Ident) {
             let ident: Ident = input.parse()?;
+            if input.peek(Token![:]) && !input.peek(Token![::]) {
+                return Err(Error::new(
+                    ident.span(),
+                    "expected a variant, found a field; `error_type!` lists variants. For a single error struct, use `#[derive(ErrorType)]` instead",
+                ));
+            }
             let fields = if input.peek(token::Brace) {
                 Fields::Named(input.parse()?)
             } else if input.peek(token::Paren) {
@@ -115,6 +153,9 @@ enum BuiltinKind {
     #[default]
     Error,
     Warn,
+    Info,
+    Note,
+    Help,
 }
 
 impl BuiltinKind {
@@ -122,6 +163,35 @@ impl BuiltinKind {
         match self {
             BuiltinKind::Error => "E",
             BuiltinKind::Warn => "W",
+            BuiltinKind::Info => "I",
+            BuiltinKind::Note => "N",
+            BuiltinKind::Help => "H",
+        }
+    }
+    fn default_label(self) -> &'static str {
+        match self {
+            BuiltinKind::Error => "error occurred here",
+            BuiltinKind::Warn => "warning occurred here",
+            BuiltinKind::Info => "info applies here",
+            BuiltinKind::Note => "note applies here",
+            BuiltinKind::Help => "help applies here",
+        }
+    }
+    /// Parse a bare built-in kind identifier, as in `#[diag(kind = Error)]`.
+    fn from_ident(expr: &Expr) -> Option<Self> {
+        let Expr::Path(path) = expr else {
+            return None;
+        };
+        if !path.attrs.is_empty() || path.qself.is_some() {
+            return None;
+        }
+        match path.path.get_ident()?.to_string().as_str() {
+            "error" | "Error" => Some(BuiltinKind::Error),
+            "warn" | "Warn" => Some(BuiltinKind::Warn),
+            "info" | "Info" => Some(BuiltinKind::Info),
+            "note" | "Note" => Some(BuiltinKind::Note),
+            "help" | "Help" => Some(BuiltinKind::Help),
+            _ => None,
         }
     }
 }
@@ -133,9 +203,12 @@ impl TryFrom<LitStr> for BuiltinKind {
         match value.value().as_str() {
             "error" | "Error" => Ok(BuiltinKind::Error),
             "warn" | "Warn" => Ok(BuiltinKind::Warn),
+            "info" | "Info" => Ok(BuiltinKind::Info),
+            "note" | "Note" => Ok(BuiltinKind::Note),
+            "help" | "Help" => Ok(BuiltinKind::Help),
             _ => Err(Error::new_spanned(
                 value,
-                "string `kind` must be either `Error` or `Warn`; use an expression for custom kinds",
+                "string `kind` must be one of `Error`, `Warn`, `Info`, `Note` or `Help`; use an expression for custom kinds",
             )),
         }
     }
@@ -146,6 +219,9 @@ impl ToTokens for BuiltinKind {
         let kind = match self {
             BuiltinKind::Error => quote! { ::error_enum::Kind::Error },
             BuiltinKind::Warn => quote! { ::error_enum::Kind::Warn },
+            BuiltinKind::Info => quote! { ::error_enum::Kind::Info },
+            BuiltinKind::Note => quote! { ::error_enum::Kind::Note },
+            BuiltinKind::Help => quote! { ::error_enum::Kind::Help },
         };
         tokens.extend(kind);
     }
@@ -182,6 +258,81 @@ impl ToTokens for KindValue {
     }
 }
 
+/// Parsed `#[diag(number_format = "...")]` template, e.g. `"{:03}"`.
+#[derive(Clone)]
+struct NumberFormat {
+    prefix: String,
+    suffix: String,
+    width: usize,
+    zero: bool,
+}
+
+impl NumberFormat {
+    fn parse(lit: &LitStr) -> Result<Self> {
+        static PLACEHOLDER: Lazy<Regex> = lazy_regex!(
+            r#"^(?<prefix>[^\{\}]*)\{(:(?<zero>0)?(?<width>\d+)?)?\}(?<suffix>[^\{\}]*)$"#
+        );
+        let value = lit.value();
+        let cap = PLACEHOLDER.captures(&value).ok_or_else(|| {
+            Error::new(
+                lit.span(),
+                "expected exactly one `{}` or `{:0N}` placeholder in `number_format`",
+            )
+        })?;
+        let width = match cap.name("width") {
+            Some(width) => width
+                .as_str()
+                .parse()
+                .map_err(|err| Error::new(lit.span(), err))?,
+            None => 0,
+        };
+        Ok(Self {
+            prefix: cap["prefix"].to_owned(),
+            suffix: cap["suffix"].to_owned(),
+            width,
+            zero: cap.name("zero").is_some(),
+        })
+    }
+    fn apply(&self, index: usize) -> String {
+        let Self {
+            prefix,
+            suffix,
+            width,
+            zero,
+        } = self;
+        if *zero {
+            format!("{prefix}{index:0width$}{suffix}")
+        } else {
+            format!("{prefix}{index:width$}{suffix}")
+        }
+    }
+}
+
+/// Placeholder syntax of message strings, from `#[diag(placeholder = "...")]` on the root.
+#[derive(Clone, Copy, Default)]
+enum PlaceholderSyntax {
+    /// Rust format syntax, `{name}`.
+    #[default]
+    Rust,
+    /// `{{name}}`, with single braces taken literally.
+    DoubleBrace,
+}
+
+impl TryFrom<&LitStr> for PlaceholderSyntax {
+    type Error = Error;
+
+    fn try_from(value: &LitStr) -> Result<Self> {
+        match value.value().as_str() {
+            "rust" => Ok(Self::Rust),
+            "double_brace" => Ok(Self::DoubleBrace),
+            _ => Err(Error::new(
+                value.span(),
+                "expected `\"rust\"` or `\"double_brace\"`",
+            )),
+        }
+    }
+}
+
 /// Configuration for each variant.
 #[derive(Clone)]
 enum SubDiagKind {
@@ -240,19 +391,62 @@ impl PendingItem {
 struct Config {
     /// Inherited / effective kind after processing this node (ancestors + local).
     kind: Option<KindValue>,
+    /// Inherited / effective severity level override, from `#[diag(level = N)]`.
+    level: Option<u8>,
     number: String,
+    /// Template for auto-numbered leaves, from `#[diag(number_format = "...")]` on the root.
+    number_format: Option<NumberFormat>,
+    /// Whether this leaf takes its number from its position via `number_format`.
+    auto_number: bool,
+    /// Placeholder syntax of message strings, translated to Rust format syntax on each leaf.
+    placeholder: PlaceholderSyntax,
+    /// Named message templates, from `#[diag(templates(...))]` on the root.
+    templates: Vec<(Ident, LitStr)>,
     msg: Option<LitStr>,
     attrs: Vec<Attribute>,
     ident: Option<Ident>,
     fields: Option<Fields>,
     span_field: Option<Ident>,
+    /// Field returned by `Error::source`, from `#[diag(source)]` on a field.
+    error_source: Option<Ident>,
+    source: Option<Expr>,
     // FIXME: move to `ErrorEnum` for better performance?
     span_type: Option<Type>,
     kind_type: Option<Type>,
+    context: Option<Type>,
+    /// Whether to generate `Error::provide`, from `#[diag(provide)]` on the root.
+    provide: bool,
+    /// Whether label-less variants get a kind-based label, from `#[diag(default_labels)]`.
+    default_labels: bool,
+    /// Whether the only field of the span type is the primary span, from `#[diag(infer_span)]`.
+    infer_span: bool,
+    /// Whether messages must end with `.`, `!` or `?`, from `#[diag(enforce_period)]`.
+    enforce_period: bool,
+    /// Whether to generate a `*Code` enum and `code_enum`, from `#[diag(code_enum)]` on the root.
+    code_enum: bool,
+    /// Prefix of codes in place of the kind's, from `#[diag(code_base = "...", separator = "...")]`
+    /// on the root, with the separator appended.
+    code_base: Option<String>,
+    /// Whether codes keep the kind prefix after `code_base`, e.g. `PARSE.E001`, from
+    /// `#[diag(kind_prefix)]` on the root.
+    code_kind_prefix: bool,
+    /// Whether to derive `Clone` for the enum, from `#[diag(clone)]` on the root of `error_type!`.
+    clone: bool,
+    /// Whether sibling numbers must count up without gaps, from `#[diag(contiguous_numbers)]`.
+    contiguous_numbers: bool,
+    context_msg: Option<LitStr>,
+    msg_verbose: Option<LitStr>,
     label: Option<LitStr>,
+    /// Replacement text for the primary span, from `#[diag(suggest = "...")]` on the variant.
+    suggest: Option<LitStr>,
+    /// Whether to generate `From<Field>` for the variant, from `#[diag(from)]` on the variant.
+    from: bool,
     pending: Vec<PendingItem>,
     depth: usize,
     nested: bool,
+    transparent: bool,
+    /// Names aliasing tuple fields, from `#[diag(arg(name = index))]`.
+    args: Vec<(Ident, usize)>,
     #[expect(unused)]
     span: Span,
 }
@@ -328,6 +522,201 @@ impl Config {
         pending.push(PendingItem::SecondaryLabel { field, text, order });
         Ok(())
     }
+    /// Replace `{code}` in every message and label of this leaf with its code.
+    ///
+    /// Skipped when a named field is called `code`, which `{code}` refers to instead.
+    fn substitute_code(&mut self) -> Result<()> {
+        if let Some(Fields::Named(named)) = &self.fields {
+            if named
+                .named
+                .iter()
+                .any(|field| field.ident.as_ref().is_some_and(|ident| ident == "code"))
+            {
+                return Ok(());
+            }
+        }
+        let kind_prefix = match &self.kind {
+            Some(KindValue::Builtin(kind)) => Some(kind.short_str()),
+            None if self.kind_type.is_none() => Some("E"),
+            Some(KindValue::Expr(_)) | None => None,
+        };
+        let code = match &self.code_base {
+            Some(code_base) if !self.code_kind_prefix => {
+                Some(format!("{code_base}{}", self.number))
+            }
+            code_base => kind_prefix.map(|kind_prefix| {
+                format!(
+                    "{}{kind_prefix}{}",
+                    code_base.as_deref().unwrap_or_default(),
+                    self.number
+                )
+            }),
+        };
+        self.for_each_message(|lit| {
+            let value = lit.value();
+            if !CODE_ARG.is_match(&value) {
+                return Ok(());
+            }
+            let Some(code) = &code else {
+                return Err(Error::new(
+                    lit.span(),
+                    "`{code}` requires a built-in `kind`; the code of a custom kind is only known at runtime",
+                ));
+            };
+            let code = code.replace('{', "{{").replace('}', "}}");
+            let value =
+                CODE_ARG.replace_all(&value, |cap: &Captures| format!("{}{code}", &cap["prefix"]));
+            *lit = LitStr::new(&value, lit.span());
+            Ok(())
+        })
+    }
+    /// Replace `{doc}` in every message and label of this leaf with the first line of its doc
+    /// comment.
+    ///
+    /// Skipped when a named field is called `doc`, which `{doc}` refers to instead.
+    fn substitute_doc(&mut self) -> Result<()> {
+        if let Some(Fields::Named(named)) = &self.fields {
+            if named
+                .named
+                .iter()
+                .any(|field| field.ident.as_ref().is_some_and(|ident| ident == "doc"))
+            {
+                return Ok(());
+            }
+        }
+        let doc = self
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("doc"))
+            .filter_map(|attr| match &attr.meta.require_name_value().ok()?.value {
+                Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(doc),
+                    ..
+                }) => Some(doc.value()),
+                _ => None,
+            })
+            .find_map(|doc| {
+                doc.lines()
+                    .map(str::trim)
+                    .find(|line| !line.is_empty())
+                    .map(str::to_owned)
+            });
+        self.for_each_message(|lit| {
+            let value = lit.value();
+            if !DOC_ARG.is_match(&value) {
+                return Ok(());
+            }
+            let Some(doc) = &doc else {
+                return Err(Error::new(
+                    lit.span(),
+                    "`{doc}` requires a doc comment on the variant",
+                ));
+            };
+            let doc = doc.replace('{', "{{").replace('}', "}}");
+            let value =
+                DOC_ARG.replace_all(&value, |cap: &Captures| format!("{}{doc}", &cap["prefix"]));
+            *lit = LitStr::new(&value, lit.span());
+            Ok(())
+        })
+    }
+    /// Whether `ty` names the span type, comparing the last path segments so that
+    /// `SimpleSpan` and `error_enum::SimpleSpan` match.
+    fn is_span_type(ty: &Type, span_type: Option<&Type>) -> bool {
+        fn last_segment(ty: &Type) -> Option<&syn::PathSegment> {
+            match ty {
+                Type::Path(path) if path.qself.is_none() => path.path.segments.last(),
+                _ => None,
+            }
+        }
+        let Some(segment) = last_segment(ty) else {
+            return false;
+        };
+        match span_type.map(last_segment) {
+            Some(Some(expected)) => {
+                segment.to_token_stream().to_string() == expected.to_token_stream().to_string()
+            }
+            Some(None) => false,
+            None => segment.ident == "SimpleSpan" && segment.arguments.is_empty(),
+        }
+    }
+    /// Check that messages end with terminal punctuation when `enforce_period` is set.
+    fn check_period(&self) -> Result<()> {
+        if !self.enforce_period {
+            return Ok(());
+        }
+        let Self {
+            msg,
+            context_msg,
+            msg_verbose,
+            ..
+        } = self;
+        for lit in [msg, context_msg, msg_verbose].into_iter().flatten() {
+            if !lit.value().trim_end().ends_with(['.', '!', '?']) {
+                return Err(Error::new(
+                    lit.span(),
+                    "message must end with `.`, `!` or `?` (required by `#[diag(enforce_period)]`)",
+                ));
+            }
+        }
+        Ok(())
+    }
+    /// Translate message strings written in the `placeholder` syntax to Rust format syntax.
+    fn translate_placeholders(&mut self) -> Result<()> {
+        static DOUBLE_BRACE: Lazy<Regex> =
+            lazy_regex!(r#"\{\{(?<arg>[^\{\}]+)\}\}|(?<brace>[\{\}])"#);
+        match self.placeholder {
+            PlaceholderSyntax::Rust => Ok(()),
+            PlaceholderSyntax::DoubleBrace => self.for_each_message(|lit| {
+                let value = lit.value();
+                let value =
+                    DOUBLE_BRACE.replace_all(&value, |cap: &Captures| match cap.name("arg") {
+                        Some(arg) => format!("{{{}}}", arg.as_str()),
+                        None => cap["brace"].repeat(2),
+                    });
+                *lit = LitStr::new(&value, lit.span());
+                Ok(())
+            }),
+        }
+    }
+    /// Apply `f` to every message and label string of this node.
+    fn for_each_message(&mut self, mut f: impl FnMut(&mut LitStr) -> Result<()>) -> Result<()> {
+        let Self {
+            msg,
+            context_msg,
+            msg_verbose,
+            label,
+            suggest,
+            pending,
+            ..
+        } = self;
+        for lit in [msg, context_msg, msg_verbose, label, suggest]
+            .into_iter()
+            .flatten()
+        {
+            f(lit)?;
+        }
+        for item in pending {
+            match item {
+                PendingItem::Note {
+                    message,
+                    label_override,
+                    ..
+                }
+                | PendingItem::Help {
+                    message,
+                    label_override,
+                    ..
+                } => {
+                    f(message)?;
+                    if let Some(label) = label_override {
+                        f(label)?;
+                    }
+                }
+                PendingItem::SecondaryLabel { text, .. } => f(text)?,
+            }
+        }
+        Ok(())
+    }
     fn finalize_diags(
         &self,
         span_field: Option<&Ident>,
@@ -443,18 +832,41 @@ impl Config {
     const fn new(span: Span) -> Self {
         Self {
             kind: None,
+            level: None,
             number: String::new(),
+            number_format: None,
+            auto_number: false,
+            placeholder: PlaceholderSyntax::Rust,
+            templates: Vec::new(),
             msg: None,
             attrs: Vec::new(),
             ident: None,
             fields: None,
             span_field: None,
+            error_source: None,
+            source: None,
             span_type: None,
             kind_type: None,
+            context: None,
+            provide: false,
+            default_labels: false,
+            infer_span: false,
+            enforce_period: false,
+            code_enum: false,
+            code_base: None,
+            code_kind_prefix: false,
+            clone: false,
+            contiguous_numbers: false,
+            context_msg: None,
+            msg_verbose: None,
             label: None,
+            suggest: None,
+            from: false,
             pending: Vec::new(),
             depth: 0,
             nested: false,
+            transparent: false,
+            args: Vec::new(),
             span,
         }
     }
@@ -467,19 +879,46 @@ impl Config {
     ) -> Result<Self> {
         let mut kind = self.kind.clone();
         let mut kind_local = None;
+        let mut level = self.level;
         let mut number = self.number.clone();
+        let mut number_local = false;
+        let mut number_format = self.number_format.clone();
+        let mut placeholder = self.placeholder;
+        let mut templates = self.templates.clone();
         let mut msg = self.msg.clone();
         let mut msg_local = false;
+        let mut context_msg = self.context_msg.clone();
+        let mut context_msg_local = false;
+        let mut msg_verbose = self.msg_verbose.clone();
+        let mut msg_verbose_local = false;
         let mut label = self.label.clone();
         let mut label_local = false;
+        let mut suggest = None;
+        let mut from = false;
         // Inherit pending for struct-level notes/helps (derive); nested ignores inherited.
         let mut pending = self.pending.clone();
         let inherited_pending_len = pending.len();
         let mut span_field = None;
+        let mut error_source = None;
+        let mut source = self.source.clone();
         let mut span_type = self.span_type.clone();
         let mut kind_type = self.kind_type.clone();
+        let mut context = self.context.clone();
+        let mut provide = self.provide;
+        let mut default_labels = self.default_labels;
+        let mut infer_span = self.infer_span;
+        let mut enforce_period = self.enforce_period;
+        let mut code_enum = self.code_enum;
+        let mut clone = self.clone;
+        let mut code_base = self.code_base.clone();
+        let mut separator: Option<LitStr> = None;
+        let mut code_kind_prefix = self.code_kind_prefix;
+        let mut kind_prefix_path = None;
+        let mut contiguous_numbers = self.contiguous_numbers;
         let depth = self.depth + 1;
         let mut nested = false;
+        let mut transparent = false;
+        let mut args = Vec::new();
         let mut unused_attrs = Vec::new();
         let mut item_order = 0usize;
 
@@ -497,6 +936,17 @@ impl Config {
                         };
                         kind_local = Some(parsed.clone());
                         kind = Some(parsed);
+                    } else if meta.path.is_ident("level") {
+                        let value: LitInt = meta.value()?.parse()?;
+                        match value.base10_parse::<u8>() {
+                            Ok(value @ 0..=3) => level = Some(value),
+                            _ => {
+                                return Err(Error::new(
+                                    value.span(),
+                                    "`level` must be 0 (error), 1 (warning), 2 (note) or 3 (none)",
+                                ))
+                            }
+                        }
                     } else if meta.path.is_ident("label") {
                         if meta.input.peek(Token![=]) {
                             let value: LitStr = meta.value()?.parse()?;
@@ -511,17 +961,154 @@ impl Config {
                         let value: LitStr = meta.value()?.parse()?;
                         msg = Some(value);
                         msg_local = true;
+                    } else if meta.path.is_ident("templates") {
+                        if self.depth != 0 {
+                            return Err(meta.error("`#[diag(templates(...))]` is only valid on the enum itself"));
+                        }
+                        meta.parse_nested_meta(|template| {
+                            let name = template
+                                .path
+                                .get_ident()
+                                .cloned()
+                                .ok_or_else(|| template.error("expected `name = \"...\"`"))?;
+                            let value: LitStr = template.value()?.parse()?;
+                            templates.push((name, value));
+                            Ok(())
+                        })?;
+                    } else if meta.path.is_ident("msg_ref") {
+                        let value: LitStr = meta.value()?.parse()?;
+                        let name = value.value();
+                        let (_, template) = templates
+                            .iter()
+                            .find(|(ident, _)| *ident == name)
+                            .ok_or_else(|| {
+                                Error::new(
+                                    value.span(),
+                                    format!("unknown message template `{name}`; declare it with `#[diag(templates({name} = \"...\"))]` on the enum"),
+                                )
+                            })?;
+                        msg = Some(LitStr::new(&template.value(), value.span()));
+                        msg_local = true;
+                    } else if meta.path.is_ident("context_msg") {
+                        let value: LitStr = meta.value()?.parse()?;
+                        context_msg = Some(value);
+                        context_msg_local = true;
+                    } else if meta.path.is_ident("msg_verbose") {
+                        let value: LitStr = meta.value()?.parse()?;
+                        msg_verbose = Some(value);
+                        msg_verbose_local = true;
+                    } else if meta.path.is_ident("suggest") {
+                        let value: LitStr = meta.value()?.parse()?;
+                        if fields.is_none() {
+                            return Err(Error::new(
+                                value.span(),
+                                "`#[diag(suggest = \"...\")]` is only valid on variants",
+                            ));
+                        }
+                        suggest = Some(value);
+                    } else if meta.path.is_ident("from") {
+                        if fields.is_none() {
+                            return Err(meta.error("`#[diag(from)]` is only valid on variants"));
+                        }
+                        from = true;
                     } else if meta.path.is_ident("nested") {
                         nested = true;
+                    } else if meta.path.is_ident("transparent") {
+                        transparent = true;
+                    } else if meta.path.is_ident("arg") {
+                        meta.parse_nested_meta(|arg| {
+                            let name = arg
+                                .path
+                                .get_ident()
+                                .cloned()
+                                .ok_or_else(|| arg.error("expected `name = index`"))?;
+                            let index: LitInt = arg.value()?.parse()?;
+                            args.push((name, index.base10_parse()?));
+                            Ok(())
+                        })?;
                     } else if meta.path.is_ident("number") {
                         let value: LitStr = meta.value()?.parse()?;
                         number.push_str(value.value().as_str());
+                        number_local = true;
+                    } else if meta.path.is_ident("number_format") {
+                        let value: LitStr = meta.value()?.parse()?;
+                        if self.depth != 0 {
+                            return Err(Error::new(
+                                value.span(),
+                                "`#[diag(number_format = \"...\")]` is only valid on the enum itself",
+                            ));
+                        }
+                        number_format = Some(NumberFormat::parse(&value)?);
+                    } else if meta.path.is_ident("placeholder") {
+                        let value: LitStr = meta.value()?.parse()?;
+                        if self.depth != 0 {
+                            return Err(Error::new(
+                                value.span(),
+                                "`#[diag(placeholder = \"...\")]` is only valid on the enum itself",
+                            ));
+                        }
+                        placeholder = (&value).try_into()?;
+                    } else if meta.path.is_ident("source") {
+                        let value: LitStr = meta.value()?.parse()?;
+                        source = Some(value.parse()?);
                     } else if meta.path.is_ident("span_type") {
                         let value: LitStr = meta.value()?.parse()?;
                         span_type = Some(value.parse()?);
                     } else if meta.path.is_ident("kind_type") {
                         let value: LitStr = meta.value()?.parse()?;
                         kind_type = Some(value.parse()?);
+                    } else if meta.path.is_ident("provide") {
+                        if self.depth != 0 {
+                            return Err(meta.error("`#[diag(provide)]` is only valid on the enum itself"));
+                        }
+                        provide = true;
+                    } else if meta.path.is_ident("default_labels") {
+                        default_labels = true;
+                    } else if meta.path.is_ident("infer_span") {
+                        if self.depth != 0 {
+                            return Err(meta.error("`#[diag(infer_span)]` is only valid on the enum itself"));
+                        }
+                        infer_span = true;
+                    } else if meta.path.is_ident("enforce_period") {
+                        if self.depth != 0 {
+                            return Err(meta.error("`#[diag(enforce_period)]` is only valid on the enum itself"));
+                        }
+                        enforce_period = true;
+                    } else if meta.path.is_ident("code_base") {
+                        if self.depth != 0 {
+                            return Err(meta.error("`#[diag(code_base)]` is only valid on the enum itself"));
+                        }
+                        let value: LitStr = meta.value()?.parse()?;
+                        code_base = Some(value.value());
+                    } else if meta.path.is_ident("separator") {
+                        if self.depth != 0 {
+                            return Err(meta.error("`#[diag(separator)]` is only valid on the enum itself"));
+                        }
+                        separator = Some(meta.value()?.parse()?);
+                    } else if meta.path.is_ident("kind_prefix") {
+                        if self.depth != 0 {
+                            return Err(meta.error("`#[diag(kind_prefix)]` is only valid on the enum itself"));
+                        }
+                        code_kind_prefix = true;
+                        kind_prefix_path = Some(meta.path.clone());
+                    } else if meta.path.is_ident("clone") {
+                        if self.depth != 0 {
+                            return Err(meta.error("`#[diag(clone)]` is only valid on the enum itself"));
+                        }
+                        clone = true;
+                    } else if meta.path.is_ident("code_enum") {
+                        if self.depth != 0 {
+                            return Err(meta.error("`#[diag(code_enum)]` is only valid on the enum itself"));
+                        }
+                        code_enum = true;
+                    } else if meta.path.is_ident("contiguous_numbers") {
+                        if self.depth != 0 {
+                            return Err(meta.error("`#[diag(contiguous_numbers)]` is only valid on the enum itself"));
+                        }
+                        contiguous_numbers = true;
+                    } else if meta.path.is_ident("context") {
+                        let value: LitStr = meta.value()?.parse()?;
+                        context = Some(value.parse()?);
                     } else if meta.path.is_ident("note") {
                         let order = item_order;
                         item_order += 1;
@@ -552,14 +1139,54 @@ impl Config {
             }
         }
 
+        if let Some(separator) = separator {
+            let Some(code_base) = &mut code_base else {
+                return Err(Error::new(
+                    separator.span(),
+                    "`#[diag(separator = \"...\")]` requires `#[diag(code_base = \"...\")]`",
+                ));
+            };
+            code_base.push_str(&separator.value());
+        }
+        if let (Some(path), None) = (&kind_prefix_path, &code_base) {
+            return Err(Error::new_spanned(
+                path,
+                "`#[diag(kind_prefix)]` requires `#[diag(code_base = \"...\")]`",
+            ));
+        }
+
         if let Some(fields) = fields {
             for (idx, field) in fields.iter().enumerate() {
                 let field_ident = field.ident.clone().unwrap_or(format_ident!("_{idx}"));
                 for attr in &field.attrs {
                     if attr.path().is_ident("diag") {
+                        // Whether this attribute marks the primary span (`Some(true)`) or a
+                        // secondary one (`Some(false)`), and whether it gave it a label.
+                        let mut span_here = None;
+                        let mut labeled = false;
                         attr.parse_nested_meta(|meta| {
                             if meta.path.is_ident("span") {
-                                span_field = Some(field_ident.clone());
+                                span_here = Some(span_field.is_none());
+                                if span_field.is_none() {
+                                    span_field = Some(field_ident.clone());
+                                }
+                            } else if let (true, Some(primary)) = (
+                                meta.path.is_ident("label") && meta.input.peek(Token![=]),
+                                span_here,
+                            ) {
+                                let text: LitStr = meta.value()?.parse()?;
+                                labeled = true;
+                                if primary {
+                                    label = Some(text);
+                                    label_local = true;
+                                } else {
+                                    pending.push(PendingItem::SecondaryLabel {
+                                        field: field_ident.clone(),
+                                        text,
+                                        order: item_order,
+                                    });
+                                    item_order += 1;
+                                }
                             } else if meta.path.is_ident("note") {
                                 let order = item_order;
                                 item_order += 1;
@@ -580,9 +1207,17 @@ impl Config {
                                     order,
                                     &mut pending,
                                 )?;
+                            } else if meta.path.is_ident("source") {
+                                if error_source.is_some() {
+                                    return Err(
+                                        meta.error("only one field may be `#[diag(source)]`")
+                                    );
+                                }
+                                error_source = Some(field_ident.clone());
                             } else if meta.path.is_ident("label") {
                                 let order = item_order;
                                 item_order += 1;
+                                labeled = true;
                                 Self::parse_secondary_label(
                                     &meta,
                                     field_ident.clone(),
@@ -593,12 +1228,35 @@ impl Config {
                                 return Err(meta.error("Unknown attribute key."));
                             }
                             Ok(())
-                        })?
+                        })?;
+                        if let (Some(false), false) = (span_here, labeled) {
+                            return Err(Error::new_spanned(
+                                attr,
+                                "only the first `#[diag(span)]` field is the primary span; label the others with `#[diag(span, label = \"...\")]`",
+                            ));
+                        }
                     }
                 }
             }
         }
 
+        if let (true, None, Some(fields)) = (infer_span && !nested, &span_field, fields) {
+            let mut candidates = fields
+                .iter()
+                .enumerate()
+                .filter(|(_, field)| Self::is_span_type(&field.ty, span_type.as_ref()));
+            if let (Some((idx, field)), None) = (candidates.next(), candidates.next()) {
+                span_field = Some(field.ident.clone().unwrap_or(format_ident!("_{idx}")));
+            }
+        }
+
+        // Without `kind_type`, bare `Error`, `Warn`, `Info`, `Note` and `Help` name the built-in kinds.
+        if let (None, Some(KindValue::Expr(expr))) = (&kind_type, &kind_local) {
+            if let Some(builtin) = BuiltinKind::from_ident(expr) {
+                kind_local = Some(KindValue::Builtin(builtin));
+                kind = kind_local.clone();
+            }
+        }
         let ident = ident.cloned();
         let fields = fields.cloned();
         if kind_type.is_some() && matches!(&kind_local, Some(KindValue::Builtin(_))) {
@@ -607,6 +1265,50 @@ impl Config {
                 "string `kind = \"...\"` is only valid with the built-in `Kind`; use `#[diag(kind = Expr)]` when `kind_type` is set",
             ));
         }
+        if let Some((name, _)) = args.first() {
+            let Some(Fields::Unnamed(unnamed)) = &fields else {
+                return Err(Error::new(
+                    name.span(),
+                    "`#[diag(arg(...))]` is only valid on tuple variants",
+                ));
+            };
+            let len = unnamed.unnamed.len();
+            if let Some((name, index)) = args.iter().find(|(_, index)| *index >= len) {
+                return Err(Error::new(
+                    name.span(),
+                    format!("tuple field index {index} is out of range for {len} field(s)"),
+                ));
+            }
+        }
+        if transparent && !nested {
+            return Err(Error::new(
+                ident.as_ref().map_or(span, Ident::span),
+                "`#[diag(transparent)]` is only valid together with `#[diag(nested)]`",
+            ));
+        }
+        if let (true, Some(fields)) = (from, &fields) {
+            if fields.len() != 1 {
+                return Err(Error::new(
+                    ident.as_ref().map_or(span, Ident::span),
+                    format!(
+                        "`#[diag(from)]` requires exactly one field, found {}",
+                        fields.len(),
+                    ),
+                ));
+            }
+        }
+        if let (Some(field), true) = (&error_source, nested) {
+            return Err(Error::new(
+                field.span(),
+                "`#[diag(source)]` is implied by `#[diag(nested)]`",
+            ));
+        }
+        if let (Some(suggest), true) = (&suggest, nested || span_field.is_none()) {
+            return Err(Error::new(
+                suggest.span(),
+                "`#[diag(suggest = \"...\")]` requires a `#[diag(span)]` field to replace",
+            ));
+        }
         if nested {
             let local_pending = &pending[inherited_pending_len..];
             Self::validate_nested(
@@ -615,29 +1317,65 @@ impl Config {
                 fields.as_ref(),
                 &kind_local,
                 msg_local,
+                context_msg_local,
+                msg_verbose_local,
                 label_local,
                 local_pending,
                 span_field.as_ref(),
             )?;
             // Nested leaves delegate message/labels/subdiagnostics.
             msg = None;
+            context_msg = None;
+            msg_verbose = None;
             label = None;
             pending.clear();
         }
+        if default_labels && label.is_none() && fields.is_some() && !nested {
+            let text = match &kind {
+                Some(KindValue::Builtin(kind)) => kind.default_label(),
+                None if kind_type.is_none() => BuiltinKind::default().default_label(),
+                Some(KindValue::Expr(_)) | None => "reported here",
+            };
+            label = Some(LitStr::new(text, span));
+        }
+        let auto_number = number_format.is_some() && fields.is_some() && !number_local;
         Ok(Self {
             kind,
+            level,
             number,
+            number_format,
+            auto_number,
+            placeholder,
+            templates,
             msg,
             attrs: unused_attrs,
             ident,
             fields,
             span_field,
+            error_source,
+            source,
             span_type,
             kind_type,
+            context,
+            provide,
+            default_labels,
+            infer_span,
+            enforce_period,
+            code_enum,
+            code_base,
+            code_kind_prefix,
+            clone,
+            contiguous_numbers,
+            context_msg,
+            msg_verbose,
             label,
+            suggest,
+            from,
             pending,
             depth,
             nested,
+            transparent,
+            args,
             span,
         })
     }
@@ -648,6 +1386,8 @@ impl Config {
         fields: Option<&Fields>,
         kind_local: &Option<KindValue>,
         msg_local: bool,
+        context_msg_local: bool,
+        msg_verbose_local: bool,
         label_local: bool,
         pending: &[PendingItem],
         span_field: Option<&Ident>,
@@ -682,6 +1422,18 @@ impl Config {
                 "`#[diag(nested)]` forbids `msg`; messages are delegated to the inner error",
             ));
         }
+        if context_msg_local {
+            return Err(Error::new(
+                err_span,
+                "`#[diag(nested)]` forbids `context_msg`; messages are delegated to the inner error",
+            ));
+        }
+        if msg_verbose_local {
+            return Err(Error::new(
+                err_span,
+                "`#[diag(nested)]` forbids `msg_verbose`; messages are delegated to the inner error",
+            ));
+        }
         if label_local {
             return Err(Error::new(
                 err_span,
@@ -705,33 +1457,98 @@ impl Config {
 }
 
 struct ErrorTreeIter<'i> {
-    stack: Vec<(punctuated::Iter<'i, ErrorTree>, Config)>,
+    /// Pending siblings, the config of their parent, and the last number seen among them.
+    stack: Vec<(punctuated::Iter<'i, ErrorTree>, Config, Option<u64>)>,
+    /// Position of the next leaf, for `number_format`.
+    leaf_index: usize,
 }
 
 impl<'i> ErrorTreeIter<'i> {
     fn new(tree: punctuated::Iter<'i, ErrorTree>, config: Config) -> Result<Self> {
         Ok(Self {
-            stack: vec![(tree, config)],
+            stack: vec![(tree, config, None)],
+            leaf_index: 0,
         })
     }
-    fn process_next(node: &'i ErrorTree, config: &Config, span: Span) -> Result<Config> {
-        let new_config = config.process(node.attrs(), node.ident(), node.fields(), span)?;
+    fn process_next(
+        node: &'i ErrorTree,
+        config: &Config,
+        span: Span,
+        leaf_index: &mut usize,
+    ) -> Result<Config> {
+        let mut new_config = config.process(node.attrs(), node.ident(), node.fields(), span)?;
+        if let ErrorTree::Variant { .. } = node {
+            if let (true, Some(format)) = (new_config.auto_number, &new_config.number_format) {
+                let number = format.apply(*leaf_index);
+                new_config.number.push_str(&number);
+            }
+            *leaf_index += 1;
+            new_config.check_period()?;
+            new_config.translate_placeholders()?;
+            new_config.substitute_code()?;
+            new_config.substitute_doc()?;
+        }
         Ok(new_config)
     }
+    /// Check that the number this node appends to its parent's directly follows the previous
+    /// sibling's, as required by `#[diag(contiguous_numbers)]`. The first sibling may start at
+    /// `0` or `1`.
+    fn check_contiguous(
+        parent: &Config,
+        config: &Config,
+        span: Span,
+        last: &mut Option<u64>,
+    ) -> Result<()> {
+        if !config.contiguous_numbers {
+            return Ok(());
+        }
+        let local = config
+            .number
+            .strip_prefix(parent.number.as_str())
+            .unwrap_or(config.number.as_str());
+        if local.is_empty() {
+            return Ok(());
+        }
+        let Ok(value) = local.parse::<u64>() else {
+            return Err(Error::new(
+                span,
+                format!("`#[diag(contiguous_numbers)]` requires decimal numbers, found `{local}`"),
+            ));
+        };
+        match *last {
+            Some(prev) if prev.checked_add(1) != Some(value) => Err(Error::new(
+                span,
+                format!(
+                    "number `{local}` leaves a gap after `{prev}`; expected `{}`",
+                    prev.saturating_add(1),
+                ),
+            )),
+            None if value > 1 => Err(Error::new(
+                span,
+                format!("number `{local}` leaves a gap; the first number should be `0` or `1`"),
+            )),
+            _ => {
+                *last = Some(value);
+                Ok(())
+            }
+        }
+    }
 }
 
 impl<'i> Iterator for ErrorTreeIter<'i> {
     type Item = Result<Config>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        while let Some((slice, config)) = self.stack.last_mut() {
+        while let Some((slice, parent, last)) = self.stack.last_mut() {
             if let Some(node) = slice.next() {
-                let config = Self::process_next(node, config, node.span())
-                    .map(Some)
-                    .transpose()?;
+                let config = Self::process_next(node, parent, node.span(), &mut self.leaf_index)
+                    .and_then(|config| {
+                        Self::check_contiguous(parent, &config, node.span(), last)?;
+                        Ok(config)
+                    });
                 if let Ok(config) = &config {
                     if let ErrorTree::Prefix { nodes, .. } = node {
-                        self.stack.push((nodes.iter(), config.clone()));
+                        self.stack.push((nodes.iter(), config.clone(), None));
                     }
                 }
                 return Some(config);
@@ -765,6 +1582,7 @@ impl ErrorEnumInner {
                     node,
                     &config,
                     node.span(),
+                    &mut 0,
                 )));
                 Ok(iter)
             }
@@ -801,8 +1619,14 @@ impl Parse for ErrorEnum {
     fn parse(input: parse::ParseStream) -> syn::Result<Self> {
         let attrs = input.call(Attribute::parse_outer)?;
         let vis = input.parse()?;
+        if input.peek(Token![struct]) {
+            return Err(input.error(
+                "`error_type!` defines an enum and takes no `struct` keyword. For a single error struct, use `#[derive(ErrorType)]` instead",
+            ));
+        }
         let name: Ident = input.parse()?;
-        let generics = input.parse()?;
+        let mut generics: Generics = input.parse()?;
+        generics.where_clause = input.parse()?;
         let children;
         let brace = braced!(children in input);
         let config = Config::new(name.span()).process(&attrs, None, None, name.span())?;
@@ -952,7 +1776,7 @@ impl ErrorEnum {
                     (None, None) => format!("{indent}- `{kind_prefix}{number}`"),
                 })
             })
-            .collect()
+            .collect_errors()
     }
     fn variants(&self) -> Result<Vec<Variant>> {
         self.iter()?
@@ -1009,12 +1833,10 @@ impl ErrorEnum {
                     discriminant: None,
                 })
             })
-            .collect()
+            .collect_errors()
     }
     fn process_unnamed_fields(msg: &str) -> Cow<'_, str> {
-        static ARG: Lazy<Regex> =
-            lazy_regex!(r#"(?<prefix>(^|[^\{])(\{\{)*)\{(?<index>\d+)(?<optional>:[^\{\}]*)?\}"#);
-        ARG.replace_all(msg, |cap: &Captures| {
+        UNNAMED_ARG.replace_all(msg, |cap: &Captures| {
             let prefix = &cap["prefix"].replace("{", "{{");
             let index = &cap["index"];
             if let Some(optional) = &cap.name("optional") {
@@ -1024,6 +1846,133 @@ impl ErrorEnum {
             }
         })
     }
+    /// Check that every positional `{index}` in `msg` refers to one of `len` tuple fields.
+    fn check_unnamed_fields(msg: &LitStr, len: usize) -> Result<()> {
+        let value = msg.value();
+        for cap in UNNAMED_ARG.captures_iter(&value) {
+            let index = &cap["index"];
+            if index.parse::<usize>().map_or(true, |index| index >= len) {
+                return Err(Error::new(
+                    msg.span(),
+                    format!("tuple field index {index} in the message is out of range for {len} field(s)"),
+                ));
+            }
+        }
+        Ok(())
+    }
+    /// Check that every named argument in `msg`, including `name$` widths and precisions, is one
+    /// of `fields`.
+    fn check_named_fields<'a>(
+        msg: &LitStr,
+        fields: impl Iterator<Item = &'a Ident> + Clone,
+    ) -> Result<()> {
+        static NAMED_ARG: Lazy<Regex> =
+            lazy_regex!(r#"\{\{|\}\}|\{(?<name>[A-Za-z_][A-Za-z0-9_]*)?(?::(?<spec>[^}]*))?\}"#);
+        static SPEC_ARG: Lazy<Regex> = lazy_regex!(r#"(?<name>[A-Za-z_][A-Za-z0-9_]*)\$"#);
+        let value = msg.value();
+        for cap in NAMED_ARG.captures_iter(&value) {
+            let names = cap.name("name").into_iter().chain(
+                cap.name("spec")
+                    .into_iter()
+                    .flat_map(|spec| SPEC_ARG.captures_iter(spec.as_str()))
+                    .filter_map(|spec| spec.name("name")),
+            );
+            for name in names {
+                let name = name.as_str();
+                if !fields.clone().any(|field| field == name) {
+                    return Err(Error::new(
+                        msg.span(),
+                        format!("`{name}` in the message is not a field of this variant"),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+    /// Reject `{0}` in the message of a tuple variant whose only field is its span, which
+    /// would otherwise fail with an obscure missing `Display` error.
+    fn check_span_only_field(msg: &LitStr) -> Result<()> {
+        if UNNAMED_ARG.is_match(&msg.value()) {
+            return Err(Error::new(
+                msg.span(),
+                "the message refers to `{0}`, the only field of this variant, which is its `#[diag(span)]`; spans are not displayed, so add a field for the text or remove `{0}` from the message",
+            ));
+        }
+        Ok(())
+    }
+    /// Rewrite each `{name:disp}` in `msg` to a binding of `name.display()`, so that paths can be
+    /// shown without the quotes of `{name:?}`. Returns the rewritten message and the bindings.
+    fn process_display_args(msg: &str) -> (Cow<'_, str>, Vec<TokenStream2>) {
+        static DISPLAY_ARG: Lazy<Regex> =
+            lazy_regex!(r#"\{\{|\}\}|\{(?<name>[A-Za-z_][A-Za-z0-9_]*):disp\}"#);
+        let mut names: Vec<String> = Vec::new();
+        let msg = DISPLAY_ARG.replace_all(msg, |cap: &Captures| match cap.name("name") {
+            Some(name) => {
+                let name = name.as_str();
+                if !names.iter().any(|seen| seen == name) {
+                    names.push(name.to_owned());
+                }
+                format!("{{__display_{name}}}")
+            }
+            None => cap[0].to_owned(),
+        });
+        let bindings = names
+            .iter()
+            .map(|name| {
+                let field = format_ident!("{name}");
+                let binding = format_ident!("__display_{name}");
+                quote! { let #binding = #field.display(); }
+            })
+            .collect();
+        (msg, bindings)
+    }
+    /// Unescape `msg` if it has no placeholders, i.e. it formats to itself with `{{` / `}}`
+    /// collapsed.
+    fn static_message(msg: &str) -> Option<String> {
+        let mut unescaped = String::with_capacity(msg.len());
+        let mut chars = msg.chars();
+        while let Some(c) = chars.next() {
+            if matches!(c, '{' | '}') && chars.next() != Some(c) {
+                return None;
+            }
+            unescaped.push(c);
+        }
+        Some(unescaped)
+    }
+    /// Expand `msg` with `format`, after rewriting positional fields of tuple variants and
+    /// `{name:disp}` arguments.
+    fn format_message(
+        msg: &LitStr,
+        unnamed: bool,
+        format: impl FnOnce(&LitStr) -> TokenStream2,
+    ) -> TokenStream2 {
+        let value = msg.value();
+        let value = if unnamed {
+            Self::process_unnamed_fields(&value)
+        } else {
+            Cow::Borrowed(value.as_str())
+        };
+        let (value, bindings) = Self::process_display_args(&value);
+        let expr = format(&LitStr::new(&value, msg.span()));
+        if bindings.is_empty() {
+            return expr;
+        }
+        quote! {{ #(#bindings)* #expr }}
+    }
+    /// Wrap `expr` in a block binding the `#[diag(arg(...))]` aliases of tuple fields.
+    fn with_arg_bindings(args: &[(Ident, usize)], expr: TokenStream2) -> TokenStream2 {
+        if args.is_empty() {
+            return expr;
+        }
+        let bindings = args.iter().map(|(name, index)| {
+            let field = format_ident!("_{index}");
+            quote! {
+                #[allow(unused_variables)]
+                let #name = #field;
+            }
+        });
+        quote! {{ #(#bindings)* #expr }}
+    }
     /// Pattern and binding for the sole field of a nested wrapper variant.
     fn nested_field(fields: &Fields) -> Result<(TokenStream2, Ident)> {
         match fields {
@@ -1044,32 +1993,67 @@ impl ErrorEnum {
             )),
         }
     }
-    fn display_branch(&self, ident: &Ident, fields: &Fields, msg: &LitStr) -> Result<TokenStream2> {
+    fn display_branch(
+        &self,
+        ident: &Ident,
+        fields: &Fields,
+        msg: &LitStr,
+        args: &[(Ident, usize)],
+        span_field: Option<&Ident>,
+    ) -> Result<TokenStream2> {
         let prefix = self.variant(ident);
         match fields {
             Fields::Named(named) => {
+                Self::check_named_fields(msg, named.named.iter().filter_map(|f| f.ident.as_ref()))?;
                 let members = named.named.iter().map(|f| f.ident.as_ref());
+                let body =
+                    Self::format_message(msg, false, |msg| quote! { ::core::write!(f, #msg) });
                 Ok(quote! {
                     #[allow(unused_variables)]
-                    #prefix { #(#members),* } => ::core::write!(f, #msg),
+                    #prefix { #(#members),* } => #body,
                 })
             }
             Fields::Unnamed(unnamed) => {
-                let params = (0..unnamed.unnamed.len()).map(|i| format_ident!("_{}", i));
-                let msg = msg.value();
-                let msg = Self::process_unnamed_fields(&msg);
+                Self::check_unnamed_fields(msg, unnamed.unnamed.len())?;
+                let positional: Vec<Ident> = (0..unnamed.unnamed.len())
+                    .map(|i| format_ident!("_{}", i))
+                    .collect();
+                Self::check_named_fields(
+                    msg,
+                    positional.iter().chain(args.iter().map(|(name, _)| name)),
+                )?;
+                if unnamed.unnamed.len() == 1 && span_field.is_some_and(|field| field == "_0") {
+                    Self::check_span_only_field(msg)?;
+                }
+                let body = Self::with_arg_bindings(
+                    args,
+                    Self::format_message(msg, true, |msg| quote! { ::core::write!(f, #msg) }),
+                );
                 Ok(quote! {
-                    #prefix ( #(#params),* ) => ::core::write!(f, #msg),
+                    #prefix ( #(#positional),* ) => #body,
                 })
             }
-            Fields::Unit => Ok(quote! {
-                #prefix => ::core::write!(f, #msg),
-            }),
-        }
+            Fields::Unit => {
+                Self::check_named_fields(msg, core::iter::empty())?;
+                Ok(quote! {
+                    #prefix => ::core::write!(f, #msg),
+                })
+            }
+        }
     }
-    fn display_nested_branch(&self, ident: &Ident, fields: &Fields) -> Result<TokenStream2> {
+    fn display_nested_branch(
+        &self,
+        ident: &Ident,
+        fields: &Fields,
+        transparent: bool,
+    ) -> Result<TokenStream2> {
         let prefix = self.variant(ident);
         let (pat, inner) = Self::nested_field(fields)?;
+        if transparent {
+            return Ok(quote! {
+                #prefix #pat => ::core::fmt::Display::fmt(#inner, f),
+            });
+        }
         Ok(quote! {
             #prefix #pat => ::core::write!(f, "{}", #inner),
         })
@@ -1084,31 +2068,313 @@ impl ErrorEnum {
                              ident,
                              fields,
                              nested,
+                             transparent,
+                             args,
+                             span_field,
                              ..
-                         }| { Some((msg, ident?, fields?, nested)) },
+                         }| {
+                            Some((msg, ident?, fields?, nested, transparent, args, span_field))
+                        },
                     )
                     .transpose()
             })
             .map(|config| {
-                let (msg, ident, fields, nested) = config?;
+                let (msg, ident, fields, nested, transparent, args, span_field) = config?;
                 if nested {
-                    return self.display_nested_branch(&ident, &fields);
+                    return self.display_nested_branch(&ident, &fields, transparent);
                 }
                 let msg = msg.ok_or_else(|| {
                     Error::new_spanned(
                         &ident,
-                        "Missing message. Consider using `#[diag(msg = \"...\")]`",
+                        "Missing message. Consider using `#[diag(msg = \"...\")]` on the variant, a prefix, or the root as a fallback",
                     )
                 })?;
-                self.display_branch(&ident, &fields, &msg)
+                self.display_branch(&ident, &fields, &msg, &args, span_field.as_ref())
+            })
+            .collect_errors()
+    }
+    /// Arms of `Error::source` forwarding to the source of the inner error of nested variants.
+    fn error_source(&self) -> Result<Vec<TokenStream2>> {
+        self.iter()?
+            .filter_map(|config| {
+                config
+                    .map(
+                        |Config {
+                             ident,
+                             fields,
+                             nested,
+                             error_source,
+                             ..
+                         }| {
+                            (nested || error_source.is_some()).then_some((
+                                ident?,
+                                fields?,
+                                error_source,
+                            ))
+                        },
+                    )
+                    .transpose()
+            })
+            .map(|config| {
+                let (ident, fields, error_source) = config?;
+                let prefix = self.variant(&ident);
+                if let Some(field) = error_source {
+                    let pat = match &fields {
+                        Fields::Named(_) => quote! { { #field, .. } },
+                        Fields::Unnamed(unnamed) => {
+                            let params = (0..unnamed.unnamed.len()).map(|i| {
+                                let param = format_ident!("_{}", i);
+                                if param == field {
+                                    quote! { #param }
+                                } else {
+                                    quote! { _ }
+                                }
+                            });
+                            quote! { ( #(#params),* ) }
+                        }
+                        Fields::Unit => quote! {},
+                    };
+                    return Ok(quote! {
+                        #prefix #pat => ::core::option::Option::Some(
+                            #field as &(dyn ::core::error::Error + 'static),
+                        ),
+                    });
+                }
+                let (pat, inner) = Self::nested_field(&fields)?;
+                Ok(quote! {
+                    #prefix #pat => ::core::error::Error::source(#inner),
+                })
+            })
+            .collect_errors()
+    }
+    /// Arms of `ErrorType::severity_level` for variants with `#[diag(level = N)]`, and
+    /// forwarding to the inner error of nested variants without one.
+    fn severity_level(&self) -> Result<Vec<TokenStream2>> {
+        self.iter()?
+            .filter_map(|config| {
+                config
+                    .map(
+                        |Config {
+                             ident,
+                             fields,
+                             level,
+                             nested,
+                             ..
+                         }| {
+                            (nested || level.is_some()).then_some((ident?, fields?, level))
+                        },
+                    )
+                    .transpose()
+            })
+            .map(|config| {
+                let (ident, fields, level) = config?;
+                let prefix = self.variant(&ident);
+                if let Some(level) = level {
+                    let branch_ignored = match fields {
+                        Fields::Named(_) => quote! { { .. } },
+                        Fields::Unnamed(_) => quote! { (..) },
+                        Fields::Unit => quote! {},
+                    };
+                    return Ok(quote! {
+                        #prefix #branch_ignored => #level,
+                    });
+                }
+                let (pat, inner) = Self::nested_field(&fields)?;
+                Ok(quote! {
+                    #prefix #pat => ::error_enum::ErrorType::severity_level(#inner),
+                })
+            })
+            .collect_errors()
+    }
+    /// Arms of `ErrorType::suggestion` for variants with `#[diag(suggest = "...")]`, and
+    /// forwarding to the inner error of nested variants.
+    fn suggestion(&self) -> Result<Vec<TokenStream2>> {
+        self.iter()?
+            .filter_map(|config| {
+                config
+                    .map(
+                        |Config {
+                             ident,
+                             fields,
+                             nested,
+                             suggest,
+                             span_field,
+                             source,
+                             args,
+                             ..
+                         }| {
+                            (nested || suggest.is_some()).then_some((
+                                ident?, fields?, nested, suggest, span_field, source, args,
+                            ))
+                        },
+                    )
+                    .transpose()
+            })
+            .map(|config| {
+                let (ident, fields, nested, suggest, span_field, source, args) = config?;
+                let prefix = self.variant(&ident);
+                if nested {
+                    let (pat, inner) = Self::nested_field(&fields)?;
+                    return Ok(quote! {
+                        #prefix #pat => ::error_enum::ErrorType::suggestion(#inner),
+                    });
+                }
+                let (Some(suggest), Some(span_field)) = (suggest, span_field) else {
+                    return Err(Error::new_spanned(
+                        &ident,
+                        "expected a suggestion and a span",
+                    ));
+                };
+                let span = self.span_from_field(&span_field, source.as_ref());
+                match &fields {
+                    Fields::Named(named) => {
+                        let members = named.named.iter().map(|f| f.ident.as_ref());
+                        let suggest = Self::format_message(&suggest, false, |suggest| {
+                            quote! { ::error_enum::format!(#suggest) }
+                        });
+                        Ok(quote! {
+                            #[allow(unused_variables)]
+                            #prefix { #(#members),* } => ::core::option::Option::Some((
+                                #span,
+                                #suggest,
+                            )),
+                        })
+                    }
+                    Fields::Unnamed(unnamed) => {
+                        Self::check_unnamed_fields(&suggest, unnamed.unnamed.len())?;
+                        let params = (0..unnamed.unnamed.len()).map(|i| format_ident!("_{}", i));
+                        let suggest = Self::format_message(&suggest, true, |suggest| {
+                            quote! { ::error_enum::format!(#suggest) }
+                        });
+                        let body = Self::with_arg_bindings(
+                            &args,
+                            quote! { ::core::option::Option::Some((#span, #suggest)) },
+                        );
+                        Ok(quote! {
+                            #[allow(unused_variables)]
+                            #prefix ( #(#params),* ) => #body,
+                        })
+                    }
+                    Fields::Unit => Err(Error::new_spanned(
+                        &ident,
+                        "`#[diag(suggest = \"...\")]` requires a `#[diag(span)]` field to replace",
+                    )),
+                }
+            })
+            .collect_errors()
+    }
+    fn context_message_branch(
+        &self,
+        ident: &Ident,
+        fields: &Fields,
+        msg: Option<&LitStr>,
+        args: &[(Ident, usize)],
+    ) -> TokenStream2 {
+        let prefix = self.variant(ident);
+        let Some(msg) = msg else {
+            let branch_ignored = match fields {
+                Fields::Named(_) => quote! { { .. } },
+                Fields::Unnamed(_) => quote! { (..) },
+                Fields::Unit => quote! {},
+            };
+            return quote! {
+                #prefix #branch_ignored => ::error_enum::format!("{self}"),
+            };
+        };
+        match fields {
+            Fields::Named(named) => {
+                let members = named.named.iter().map(|f| f.ident.as_ref());
+                let body =
+                    Self::format_message(msg, false, |msg| quote! { ::error_enum::format!(#msg) });
+                quote! {
+                    #[allow(unused_variables)]
+                    #prefix { #(#members),* } => #body,
+                }
+            }
+            Fields::Unnamed(unnamed) => {
+                let params = (0..unnamed.unnamed.len()).map(|i| format_ident!("_{}", i));
+                let body = Self::with_arg_bindings(
+                    args,
+                    Self::format_message(msg, true, |msg| quote! { ::error_enum::format!(#msg) }),
+                );
+                quote! {
+                    #[allow(unused_variables)]
+                    #prefix ( #(#params),* ) => #body,
+                }
+            }
+            Fields::Unit => quote! {
+                #prefix => ::error_enum::format!(#msg),
+            },
+        }
+    }
+    fn context_message(&self) -> Result<Vec<TokenStream2>> {
+        self.iter()?
+            .filter_map(|config| {
+                config
+                    .map(
+                        |Config {
+                             context_msg,
+                             ident,
+                             fields,
+                             args,
+                             ..
+                         }| { Some((context_msg, ident?, fields?, args)) },
+                    )
+                    .transpose()
+            })
+            .map(|config| {
+                let (context_msg, ident, fields, args) = config?;
+                Ok(self.context_message_branch(&ident, &fields, context_msg.as_ref(), &args))
+            })
+            .collect_errors()
+    }
+    fn verbose_message(&self) -> Result<Vec<TokenStream2>> {
+        self.iter()?
+            .filter_map(|config| {
+                config
+                    .map(
+                        |Config {
+                             msg_verbose,
+                             ident,
+                             fields,
+                             nested,
+                             args,
+                             ..
+                         }| {
+                            Some((msg_verbose, ident?, fields?, nested, args))
+                        },
+                    )
+                    .transpose()
+            })
+            .map(|config| {
+                let (msg_verbose, ident, fields, nested, args) = config?;
+                if nested {
+                    let prefix = self.variant(&ident);
+                    let (pat, inner) = Self::nested_field(&fields)?;
+                    return Ok(quote! {
+                        #prefix #pat => ::error_enum::ErrorType::primary_message_verbose(#inner),
+                    });
+                }
+                Ok(self.context_message_branch(&ident, &fields, msg_verbose.as_ref(), &args))
             })
-            .collect()
+            .collect_errors()
+    }
+    /// Convert a span-bearing field into the span type, combined with `#[diag(source)]` if any.
+    fn span_from_field(&self, field: &Ident, source: Option<&Expr>) -> TokenStream2 {
+        let span_type = self.span_type();
+        match source {
+            Some(source) => {
+                quote! { <#span_type as ::core::convert::From<_>>::from((#source, #field)) }
+            }
+            None => quote! { <#span_type as ::core::convert::From<_>>::from(#field) },
+        }
     }
     fn label_vec1_codegen(
         &self,
         entries: &[LabelEntry],
         unnamed: bool,
         spanless: bool,
+        source: Option<&Expr>,
     ) -> TokenStream2 {
         let span_type = self.span_type();
         let pairs = entries.iter().map(|entry| {
@@ -1117,15 +2383,14 @@ impl ErrorEnum {
             let span_expr = if spanless {
                 quote! { <#span_type as ::core::default::Default>::default() }
             } else {
-                quote! { <#span_type as ::core::convert::From<_>>::from(#field) }
+                self.span_from_field(field, source)
             };
-            if unnamed {
-                let value = text.value();
-                let value = Self::process_unnamed_fields(&value);
-                quote! { (#span_expr, ::error_enum::format!(#value)) }
-            } else {
-                quote! { (#span_expr, ::error_enum::format!(#text)) }
-            }
+            let text = Self::format_message(
+                text,
+                unnamed,
+                |text| quote! { ::error_enum::format!(#text) },
+            );
+            quote! { (#span_expr, #text) }
         });
         quote! { ::error_enum::vec1![ #(#pairs),* ] }
     }
@@ -1140,17 +2405,23 @@ impl ErrorEnum {
                              fields,
                              label,
                              span_field,
+                             source,
                              pending,
                              nested,
+                             args,
                              ..
                          }| {
-                            Some((msg, ident?, fields?, label, span_field, pending, nested))
+                            Some((
+                                msg, ident?, fields?, label, span_field, source, pending, nested,
+                                args,
+                            ))
                         },
                     )
                     .transpose()
             })
             .map(|config| {
-                let (msg, ident, fields, label, span_field, pending, nested) = config?;
+                let (msg, ident, fields, label, span_field, source, pending, nested, args) =
+                    config?;
                 if nested {
                     let prefix = self.variant(&ident);
                     let (pat, inner) = Self::nested_field(&fields)?;
@@ -1164,22 +2435,32 @@ impl ErrorEnum {
                 };
                 let (primary_labels, _) =
                     config.finalize_diags(span_field.as_ref(), &label, &msg, &ident)?;
-                self.primary_labels_branch(&ident, &fields, span_field.as_ref(), &primary_labels)
+                self.primary_labels_branch(
+                    &ident,
+                    &fields,
+                    span_field.as_ref(),
+                    source.as_ref(),
+                    &primary_labels,
+                    &args,
+                )
             })
-            .collect()
+            .collect_errors()
     }
     fn primary_labels_branch(
         &self,
         ident: &Ident,
         fields: &Fields,
         span_field: Option<&Ident>,
+        source: Option<&Expr>,
         entries: &[LabelEntry],
+        args: &[(Ident, usize)],
     ) -> Result<TokenStream2> {
         let prefix = self.variant(ident);
         let labels = self.label_vec1_codegen(
             entries,
             matches!(fields, Fields::Unnamed(_)),
             span_field.is_none(),
+            source,
         );
         match fields {
             Fields::Named(named) => {
@@ -1191,6 +2472,7 @@ impl ErrorEnum {
             }
             Fields::Unnamed(unnamed) => {
                 let params = (0..unnamed.unnamed.len()).map(|i| format_ident!("_{}", i));
+                let labels = Self::with_arg_bindings(args, labels);
                 Ok(quote! {
                     #prefix ( #(#params),* ) => #labels,
                 })
@@ -1200,17 +2482,18 @@ impl ErrorEnum {
             }),
         }
     }
-    fn additional_unit_tokens(&self, unit: &SubDiagnosticUnit, unnamed: bool) -> TokenStream2 {
+    fn additional_unit_tokens(
+        &self,
+        unit: &SubDiagnosticUnit,
+        unnamed: bool,
+        source: Option<&Expr>,
+    ) -> TokenStream2 {
         let spanless = unit.field.is_none();
-        let labels = self.label_vec1_codegen(&unit.labels, unnamed, spanless);
+        let labels = self.label_vec1_codegen(&unit.labels, unnamed, spanless, source);
         let message = &unit.message;
-        let message_fmt = if unnamed {
-            let value = message.value();
-            let value = Self::process_unnamed_fields(&value);
-            quote! { ::error_enum::format!(#value) }
-        } else {
+        let message_fmt = Self::format_message(message, unnamed, |message| {
             quote! { ::error_enum::format!(#message) }
-        };
+        });
         let kind = match unit.kind {
             SubDiagKind::Note => quote! { ::error_enum::AdditionalKind::Note },
             SubDiagKind::Help => quote! { ::error_enum::AdditionalKind::Help },
@@ -1227,14 +2510,16 @@ impl ErrorEnum {
         &self,
         ident: &Ident,
         fields: &Fields,
+        source: Option<&Expr>,
         units: &[SubDiagnosticUnit],
+        args: &[(Ident, usize)],
     ) -> Result<TokenStream2> {
         let prefix = self.variant(ident);
         let box_type: syn::Expr = parse_quote!(::error_enum::Box);
         let unnamed = matches!(fields, Fields::Unnamed(_));
         let additional = units
             .iter()
-            .map(|unit| self.additional_unit_tokens(unit, unnamed));
+            .map(|unit| self.additional_unit_tokens(unit, unnamed, source));
         match fields {
             Fields::Named(named) => {
                 let members = named.named.iter().map(|f| f.ident.as_ref());
@@ -1247,14 +2532,22 @@ impl ErrorEnum {
             }
             Fields::Unnamed(unnamed_fields) => {
                 let params = (0..unnamed_fields.unnamed.len()).map(|i| format_ident!("_{}", i));
+                let body = Self::with_arg_bindings(
+                    args,
+                    quote! {
+                        #box_type::new([
+                            #(#additional,)*
+                        ].into_iter())
+                    },
+                );
                 Ok(quote! {
-                    #prefix ( #(#params),* ) => #box_type::new([
-                        #(#additional,)*
-                    ].into_iter()),
+                    #prefix ( #(#params),* ) => #body,
                 })
             }
             Fields::Unit => Ok(quote! {
-                #prefix => #box_type::new([].into_iter()),
+                #prefix => #box_type::new([
+                    #(#additional,)*
+                ].into_iter()),
             }),
         }
     }
@@ -1269,17 +2562,23 @@ impl ErrorEnum {
                              msg,
                              label,
                              span_field,
+                             source,
                              pending,
                              nested,
+                             args,
                              ..
                          }| {
-                            Some((ident?, fields?, msg, label, span_field, pending, nested))
+                            Some((
+                                ident?, fields?, msg, label, span_field, source, pending, nested,
+                                args,
+                            ))
                         },
                     )
                     .transpose()
             })
             .map(|config| {
-                let (ident, fields, msg, label, span_field, pending, nested) = config?;
+                let (ident, fields, msg, label, span_field, source, pending, nested, args) =
+                    config?;
                 if nested {
                     let prefix = self.variant(&ident);
                     let (pat, inner) = Self::nested_field(&fields)?;
@@ -1293,15 +2592,104 @@ impl ErrorEnum {
                 };
                 let (_, units) =
                     config.finalize_diags(span_field.as_ref(), &label, &msg, &ident)?;
-                self.additional_branch(&ident, &fields, &units)
+                self.additional_branch(&ident, &fields, source.as_ref(), &units, &args)
+            })
+            .collect_errors()
+    }
+    /// Compile-time code prefix when known (`code_base`, the kind's `E` / `W` / ..., or both
+    /// with `kind_prefix`); `None` for expression kinds whose prefix is needed.
+    fn static_code_prefix(&self, kind: Option<&KindValue>) -> Option<String> {
+        let code_base = self.config.code_base.as_deref();
+        if let (Some(code_base), false) = (code_base, self.config.code_kind_prefix) {
+            return Some(code_base.to_owned());
+        }
+        let kind_prefix = match kind {
+            Some(KindValue::Builtin(k)) => k.short_str(),
+            Some(KindValue::Expr(_)) => return None,
+            None if self.config.kind_type.is_none() => "E",
+            None => return None,
+        };
+        Some(format!("{}{kind_prefix}", code_base.unwrap_or_default()))
+    }
+    /// Leaves with their codes in declaration order, or `None` if any code is only known at
+    /// runtime, i.e. nested or of an expression kind.
+    fn all_codes(&self) -> Result<Option<Vec<(Ident, Fields, String)>>> {
+        let codes: Vec<Option<(Ident, Fields, String)>> = self
+            .iter()?
+            .filter_map(|config| {
+                config
+                    .map(
+                        |Config {
+                             ident,
+                             fields,
+                             kind,
+                             number,
+                             nested,
+                             ..
+                         }| {
+                            Some((ident?, fields?, kind, number, nested))
+                        },
+                    )
+                    .transpose()
+            })
+            .map(|config| {
+                let (ident, fields, kind, number, nested) = config?;
+                let prefix = self.static_code_prefix(kind.as_ref()).filter(|_| !nested);
+                Ok(prefix.map(|prefix| (ident, fields, format!("{prefix}{number}"))))
+            })
+            .collect_errors()?;
+        Ok(codes.into_iter().collect())
+    }
+    /// Statements pushing the kind and number of each leaf onto `all`, expanding nested leaves
+    /// through `ErrorType::kinds_and_numbers` of the inner type.
+    fn kinds_and_numbers(&self) -> Result<Vec<TokenStream2>> {
+        let kind_type = self.kind_type();
+        self.iter()?
+            .filter_map(|config| {
+                config
+                    .map(
+                        |Config {
+                             fields,
+                             kind,
+                             number,
+                             nested,
+                             ..
+                         }| { Some((fields?, kind, number, nested)) },
+                    )
+                    .transpose()
             })
-            .collect()
+            .map(|config| {
+                let (fields, kind, number, nested) = config?;
+                if nested {
+                    let inner = fields.iter().next().map(|field| &field.ty).ok_or_else(|| {
+                        Error::new(Span::call_site(), "`#[diag(nested)]` requires exactly one field")
+                    })?;
+                    return Ok(quote! {
+                        all.extend(
+                            <#inner as ::error_enum::ErrorType>::kinds_and_numbers()
+                                .into_iter()
+                                .map(|(kind, number)| (kind, ::error_enum::format!("{}{}", #number, number))),
+                        );
+                    });
+                }
+                let kind = kind.unwrap_or_else(|| {
+                    KindValue::Expr(parse_quote! {
+                        <#kind_type as ::core::default::Default>::default()
+                    })
+                });
+                Ok(quote! {
+                    all.push((#kind, ::error_enum::String::from(#number)));
+                })
+            })
+            .collect_errors()
     }
+    #[expect(clippy::too_many_arguments)]
     fn impl_error_enum_branch(
         &self,
         ident: &Ident,
         fields: &Fields,
         span_field: Option<Ident>,
+        source: Option<&Expr>,
         kind: Option<&KindValue>,
         number: &str,
         nested: bool,
@@ -1309,13 +2697,9 @@ impl ErrorEnum {
         let prefix = self.variant(ident);
         let kind_type = self.kind_type();
 
-        // Compile-time code prefix when known (`E` / `W`); `None` for expression kinds.
-        let static_prefix = match kind {
-            Some(KindValue::Builtin(k)) => Some(k.short_str()),
-            Some(KindValue::Expr(_)) => None,
-            None if self.config.kind_type.is_none() => Some("E"),
-            None => None,
-        };
+        let static_prefix = self.static_code_prefix(kind);
+        // `code_base` before a kind prefix only known at runtime, with `kind_prefix`.
+        let code_base = self.config.code_base.as_ref().map(|base| quote! { #base, });
 
         if nested {
             let (pat, inner) = Self::nested_field(fields)?;
@@ -1342,15 +2726,34 @@ impl ErrorEnum {
                     ::error_enum::ErrorType::number(#inner)
                 )),
             };
-            let code_arm = quote! {
-                #prefix #pat => {
-                    let __kind = ::error_enum::ErrorType::kind(#inner);
-                    ::error_enum::Cow::Owned(::error_enum::format!(
+            let code_format = if code_base.is_some() {
+                "{}{}{}{}"
+            } else {
+                "{}{}{}"
+            };
+            let code_arm = if let (Some(code_base), false) =
+                (&self.config.code_base, self.config.code_kind_prefix)
+            {
+                quote! {
+                    #prefix #pat => ::error_enum::Cow::Owned(::error_enum::format!(
                         "{}{}{}",
-                        ::error_enum::DiagnosticKind::code_prefix(&__kind),
+                        #code_base,
                         #number,
                         ::error_enum::ErrorType::number(#inner)
-                    ))
+                    )),
+                }
+            } else {
+                quote! {
+                    #prefix #pat => {
+                        let __kind = ::error_enum::ErrorType::kind(#inner);
+                        ::error_enum::Cow::Owned(::error_enum::format!(
+                            #code_format,
+                            #code_base
+                            ::error_enum::DiagnosticKind::code_prefix(&__kind),
+                            #number,
+                            ::error_enum::ErrorType::number(#inner)
+                        ))
+                    }
                 }
             };
             let primary_span_arm = quote! {
@@ -1382,17 +2785,23 @@ impl ErrorEnum {
                 #prefix #branch_ignored => ::error_enum::Cow::Borrowed(#code_lit),
             }
         } else {
+            let code_format = if code_base.is_some() {
+                "{}{}{}"
+            } else {
+                "{}{}"
+            };
             quote! {
                 #prefix #branch_ignored => ::error_enum::Cow::Owned(::error_enum::format!(
-                    "{}{}",
+                    #code_format,
+                    #code_base
                     ::error_enum::DiagnosticKind::code_prefix(&(#kind_expr)),
                     #number
                 )),
             }
         };
-        let span_type = self.span_type();
         let span = if let Some(span_field) = span_field {
-            quote! {::core::option::Option::Some(<#span_type as ::core::convert::From<_>>::from(#span_field))}
+            let span = self.span_from_field(&span_field, source);
+            quote! {::core::option::Option::Some(#span)}
         } else {
             quote! {::core::option::Option::None}
         };
@@ -1428,26 +2837,110 @@ impl ErrorEnum {
                              kind,
                              number,
                              span_field,
+                             source,
                              nested,
                              ..
                          }| {
-                            Some((ident?, fields?, kind, number, span_field, nested))
+                            Some((ident?, fields?, kind, number, span_field, source, nested))
                         },
                     )
                     .transpose()
             })
             .map(|config| {
-                let (ident, fields, kind, number, span_field, nested) = config?;
+                let (ident, fields, kind, number, span_field, source, nested) = config?;
                 self.impl_error_enum_branch(
                     &ident,
                     &fields,
                     span_field,
+                    source.as_ref(),
                     kind.as_ref(),
                     &number,
                     nested,
                 )
             })
-            .collect()
+            .collect_errors()
+    }
+    fn templates(&self) -> Result<(Vec<TokenStream2>, Vec<TokenStream2>)> {
+        self.iter()?
+            .filter_map(|config| {
+                config
+                    .map(
+                        |Config {
+                             ident,
+                             fields,
+                             msg,
+                             label,
+                             nested,
+                             ..
+                         }| { Some((ident?, fields?, msg, label, nested)) },
+                    )
+                    .transpose()
+            })
+            .map(|config| {
+                let (ident, fields, msg, label, nested) = config?;
+                let prefix = self.variant(&ident);
+                if nested {
+                    let (pat, inner) = Self::nested_field(&fields)?;
+                    return Ok((
+                        quote! {
+                            #prefix #pat => ::error_enum::ErrorType::message_template(#inner),
+                        },
+                        quote! {
+                            #prefix #pat => ::error_enum::ErrorType::primary_label_template(#inner),
+                        },
+                    ));
+                }
+                let branch_ignored = match fields {
+                    Fields::Named(_) => quote! { { .. } },
+                    Fields::Unnamed(_) => quote! { (..) },
+                    Fields::Unit => quote! {},
+                };
+                let msg = msg.map(|msg| msg.value()).unwrap_or_default();
+                let label = label.map_or_else(|| msg.clone(), |label| label.value());
+                Ok((
+                    quote! { #prefix #branch_ignored => #msg, },
+                    quote! { #prefix #branch_ignored => #label, },
+                ))
+            })
+            .collect_errors()
+    }
+    /// Arms of `ErrorType::primary_message_static`, `Some` for messages without placeholders.
+    fn static_messages(&self) -> Result<Vec<TokenStream2>> {
+        self.iter()?
+            .filter_map(|config| {
+                config
+                    .map(
+                        |Config {
+                             ident,
+                             fields,
+                             msg,
+                             nested,
+                             ..
+                         }| { Some((ident?, fields?, msg, nested)) },
+                    )
+                    .transpose()
+            })
+            .map(|config| {
+                let (ident, fields, msg, nested) = config?;
+                let prefix = self.variant(&ident);
+                if nested {
+                    let (pat, inner) = Self::nested_field(&fields)?;
+                    return Ok(quote! {
+                        #prefix #pat => ::error_enum::ErrorType::primary_message_static(#inner),
+                    });
+                }
+                let branch_ignored = match fields {
+                    Fields::Named(_) => quote! { { .. } },
+                    Fields::Unnamed(_) => quote! { (..) },
+                    Fields::Unit => quote! {},
+                };
+                let msg = match msg.and_then(|msg| Self::static_message(&msg.value())) {
+                    Some(msg) => quote! { ::core::option::Option::Some(#msg) },
+                    None => quote! { ::core::option::Option::None },
+                };
+                Ok(quote! { #prefix #branch_ignored => #msg, })
+            })
+            .collect_errors()
     }
     fn span_type(&self) -> Cow<'_, Type> {
         self.config.span_type.as_ref().map_or_else(
@@ -1469,6 +2962,124 @@ impl ErrorEnum {
             Cow::Borrowed,
         )
     }
+    /// Scrutinee of the generated matches: `*self` for enums without variants, as an empty
+    /// match must be on the uninhabited place rather than on a reference to it.
+    fn scrutinee(&self) -> Result<TokenStream2> {
+        let has_leaf = self
+            .iter()?
+            .any(|config| config.is_ok_and(|config| config.fields.is_some()));
+        Ok(if has_leaf {
+            quote! { self }
+        } else {
+            quote! { *self }
+        })
+    }
+    /// Generate the `*Code` enum with one unit variant per leaf, and `code_enum` mapping to it.
+    fn code_enum(&self, tokens: &mut TokenStream2) -> Result<()> {
+        if !self.is_enum() {
+            return Err(Error::new(
+                self.name.span(),
+                "`#[diag(code_enum)]` is only valid on enums",
+            ));
+        }
+        let vis = &self.vis;
+        let name = &self.name;
+        let scrutinee = self.scrutinee()?;
+        let code_name = format_ident!("{}Code", name);
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let leaves: Vec<(Ident, Fields)> = self
+            .iter()?
+            .filter_map(|config| {
+                config
+                    .map(|Config { ident, fields, .. }| Some((ident?, fields?)))
+                    .transpose()
+            })
+            .collect_errors()?;
+        let enum_doc = format!("Codes of [`{name}`], one variant per error variant.");
+        let variants = leaves.iter().map(|(ident, _)| {
+            let doc = format!("Code of [`{name}::{ident}`].");
+            quote! {
+                #[doc = #doc]
+                #ident,
+            }
+        });
+        let arms = leaves.iter().map(|(ident, fields)| {
+            let prefix = self.variant(ident);
+            let branch_ignored = match fields {
+                Fields::Named(_) => quote! { { .. } },
+                Fields::Unnamed(_) => quote! { (..) },
+                Fields::Unit => quote! {},
+            };
+            quote! {
+                #prefix #branch_ignored => #code_name::#ident,
+            }
+        });
+        tokens.extend(quote! {
+            #[doc = #enum_doc]
+            #[derive(
+                ::core::clone::Clone,
+                ::core::marker::Copy,
+                ::core::fmt::Debug,
+                ::core::cmp::PartialEq,
+                ::core::cmp::Eq,
+                ::core::hash::Hash,
+            )]
+            #vis enum #code_name {
+                #(#variants)*
+            }
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// Get the data-less code of the error, for exhaustive matching.
+                #vis fn code_enum(&self) -> #code_name {
+                    match #scrutinee {
+                        #(#arms)*
+                    }
+                }
+            }
+        });
+        Ok(())
+    }
+    /// Generate `From<Field>` for each leaf with `#[diag(from)]`.
+    fn impl_from(&self, tokens: &mut TokenStream2) -> Result<()> {
+        let name = &self.name;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let leaves: Vec<(Ident, Fields)> = self
+            .iter()?
+            .filter_map(|config| {
+                config
+                    .map(
+                        |Config {
+                             ident,
+                             fields,
+                             from,
+                             ..
+                         }| { from.then_some((ident?, fields?)) },
+                    )
+                    .transpose()
+            })
+            .collect_errors()?;
+        for (ident, fields) in leaves {
+            let prefix = self.variant(&ident);
+            let Some(field) = fields.iter().next() else {
+                return Err(Error::new(
+                    ident.span(),
+                    "`#[diag(from)]` requires exactly one field, found 0",
+                ));
+            };
+            let ty = &field.ty;
+            let construct = match &field.ident {
+                Some(field) => quote! { #prefix { #field: value } },
+                None => quote! { #prefix(value) },
+            };
+            tokens.extend(quote! {
+                impl #impl_generics ::core::convert::From<#ty> for #name #ty_generics #where_clause {
+                    fn from(value: #ty) -> Self {
+                        #construct
+                    }
+                }
+            });
+        }
+        Ok(())
+    }
     fn try_to_tokens(&self, tokens: &mut TokenStream2) -> Result<()> {
         let attrs: Vec<&Attribute> = self
             .attrs
@@ -1478,6 +3089,7 @@ impl ErrorEnum {
         let vis = &self.vis;
         let name = &self.name;
         let generics = &self.generics;
+        let scrutinee = self.scrutinee()?;
 
         let doc = self.doc()?;
 
@@ -1489,34 +3101,199 @@ impl ErrorEnum {
             body: true, brace, ..
         } = self.inner
         {
+            let clone = self
+                .config
+                .clone
+                .then(|| quote! { #[derive(::core::clone::Clone)] });
             tokens.extend(quote! {
                 #(#attrs)*
+                #clone
                 #[doc = "List of error variants:"]
                 #(
                     #[doc = #doc]
                 )*
-                #vis enum #name #generics
+                #vis enum #name #generics #where_clause
             });
             brace.surround(tokens, |tokens| {
                 tokens.extend(quote! { #(#variants, )* });
             });
         }
+        if self.config.clone && !matches!(self.inner, ErrorEnumInner::Multiple { body: true, .. }) {
+            return Err(Error::new(
+                name.span(),
+                "`#[diag(clone)]` is only valid in `error_type!`; add `#[derive(Clone)]` instead",
+            ));
+        }
 
         let display = self.display()?;
+        let provide = self.config.provide.then(|| {
+            let span_type = self.span_type();
+            quote! {
+                fn provide<'a>(&'a self, request: &mut ::core::error::Request<'a>) {
+                    if let ::core::option::Option::Some(span) = ::error_enum::ErrorType::primary_span(self) {
+                        request.provide_value::<#span_type>(span);
+                    }
+                    request.provide_value::<::error_enum::String>(
+                        ::error_enum::String::from(::error_enum::ErrorType::code(self)),
+                    );
+                }
+            }
+        });
+        let error_source = self.error_source()?;
+        let source = (!error_source.is_empty()).then(|| {
+            quote! {
+                fn source(&self) -> ::core::option::Option<&(dyn ::core::error::Error + 'static)> {
+                    #[allow(unreachable_patterns)]
+                    match #scrutinee {
+                        #(#error_source)*
+                        _ => ::core::option::Option::None,
+                    }
+                }
+            }
+        });
         tokens.extend(quote! {
             impl #impl_generics ::core::fmt::Display for #name #ty_generics #where_clause {
                 fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                    match self {
+                    match #scrutinee {
                         #(#display)*
                     }
                 }
             }
-            impl #impl_generics ::core::error::Error for #name #ty_generics #where_clause {}
+            impl #impl_generics ::core::error::Error for #name #ty_generics #where_clause {
+                #source
+                #provide
+            }
         });
 
+        if self.config.code_enum {
+            self.code_enum(tokens)?;
+        }
+        self.impl_from(tokens)?;
+
+        if let Some(leaves) = self.all_codes()? {
+            let codes = leaves.iter().map(|(_, _, code)| code);
+            // Only unit variants can be rebuilt from their code alone.
+            let from_code = self.is_enum().then(|| {
+                let arms = leaves
+                    .iter()
+                    .filter(|(_, fields, _)| matches!(fields, Fields::Unit))
+                    .map(|(ident, _, code)| {
+                        let prefix = self.variant(ident);
+                        quote! { #code => ::core::option::Option::Some(#prefix), }
+                    });
+                quote! {
+                    /// Get the unit variant with the given code, or `None` if there is none.
+                    #vis fn from_code(code: &::core::primitive::str) -> ::core::option::Option<Self> {
+                        #[allow(unreachable_patterns)]
+                        match code {
+                            #(#arms)*
+                            _ => ::core::option::Option::None,
+                        }
+                    }
+                }
+            });
+            tokens.extend(quote! {
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// Codes of all variants, in declaration order.
+                    #vis const ALL_CODES: &'static [&'static ::core::primitive::str] = &[#(#codes),*];
+                    #from_code
+                }
+            });
+        }
+
+        if let Some(context) = &self.config.context {
+            let context_message = self.context_message()?;
+            tokens.extend(quote! {
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// Get the primary message of the error rendered with the shared context.
+                    ///
+                    /// Variants without `#[diag(context_msg = "...")]` fall back to [`Display`](::core::fmt::Display).
+                    #[allow(unused_variables)]
+                    #vis fn primary_message_with(&self, ctx: &#context) -> ::error_enum::String {
+                        match #scrutinee {
+                            #(#context_message)*
+                        }
+                    }
+                }
+            });
+        }
+
         let (kind, number, code, primary_span) = self.impl_error_enum()?;
         let primary_labels = self.primary_labels()?;
         let additional = self.additional()?;
+        let (message_template, primary_label_template) = self.templates()?;
+        let static_messages = self.static_messages()?;
+        let kinds_and_numbers = self.kinds_and_numbers()?;
+        let code_with_kind = self.config.code_base.as_ref().map(|code_base| {
+            if self.config.code_kind_prefix {
+                quote! {
+                    fn code_with_kind(&self, kind: &Self::Kind) -> ::error_enum::Cow<'_, ::core::primitive::str> {
+                        ::error_enum::Cow::Owned(::error_enum::format!(
+                            "{}{}{}",
+                            #code_base,
+                            ::error_enum::DiagnosticKind::code_prefix(kind),
+                            ::error_enum::ErrorType::number(self)
+                        ))
+                    }
+                }
+            } else {
+                // Without the kind prefix, the code doesn't depend on the kind.
+                quote! {
+                    fn code_with_kind(&self, _kind: &Self::Kind) -> ::error_enum::Cow<'_, ::core::primitive::str> {
+                        ::error_enum::ErrorType::code(self)
+                    }
+                }
+            }
+        });
+        let all_codes = self.config.code_base.as_ref().map(|code_base| {
+            let code = if self.config.code_kind_prefix {
+                quote! {
+                    |(kind, number)| ::error_enum::format!(
+                        "{}{}{}",
+                        #code_base,
+                        ::error_enum::DiagnosticKind::code_prefix(&kind),
+                        number
+                    )
+                }
+            } else {
+                quote! { |(_, number)| ::error_enum::format!("{}{}", #code_base, number) }
+            };
+            quote! {
+                fn all_codes() -> ::error_enum::Vec<::error_enum::String> {
+                    <Self as ::error_enum::ErrorType>::kinds_and_numbers()
+                        .into_iter()
+                        .map(#code)
+                        .collect()
+                }
+            }
+        });
+        let verbose_message = self.verbose_message()?;
+        let severity_level = self.severity_level()?;
+        let severity_level = (!severity_level.is_empty()).then(|| {
+            quote! {
+                fn severity_level(&self) -> ::core::primitive::u8 {
+                    #[allow(unreachable_patterns)]
+                    match #scrutinee {
+                        #(#severity_level)*
+                        _ => ::error_enum::DiagnosticKind::severity_level(
+                            &::error_enum::ErrorType::kind(self),
+                        ),
+                    }
+                }
+            }
+        });
+        let suggestion = self.suggestion()?;
+        let suggestion = (!suggestion.is_empty()).then(|| {
+            quote! {
+                fn suggestion(&self) -> ::core::option::Option<(Self::Span, ::error_enum::String)> {
+                    #[allow(unreachable_patterns)]
+                    match #scrutinee {
+                        #(#suggestion)*
+                        _ => ::core::option::Option::None,
+                    }
+                }
+            }
+        });
         let span_type = self.span_type();
         let kind_type = self.kind_type();
         let option_span_type: Type = parse_quote!(::core::option::Option<#span_type>);
@@ -1531,38 +3308,68 @@ impl ErrorEnum {
                 type Label = #msg_type;
 
                 fn kind(&self) -> Self::Kind {
-                    match self {
+                    match #scrutinee {
                         #(#kind)*
                     }
                 }
                 fn number(&self) -> ::error_enum::Cow<'_, ::core::primitive::str> {
-                    match self {
+                    match #scrutinee {
                         #(#number)*
                     }
                 }
                 fn code(&self) -> ::error_enum::Cow<'_, ::core::primitive::str> {
-                    match self {
+                    match #scrutinee {
                         #(#code)*
                     }
                 }
+                #code_with_kind
                 fn primary_span(&self) -> #option_span_type {
-                    match self {
+                    match #scrutinee {
                         #(#primary_span)*
                     }
                 }
                 fn primary_message(&self) -> #msg_type {
                     ::error_enum::format!("{self}")
                 }
+                fn primary_message_verbose(&self) -> #msg_type {
+                    match #scrutinee {
+                        #(#verbose_message)*
+                    }
+                }
                 fn primary_labels(&self) -> ::error_enum::LabelVec1<#span_type, #msg_type> {
-                    match self {
+                    match #scrutinee {
                         #(#primary_labels)*
                     }
                 }
                 fn additional(&self) -> #box_type<dyn #iterator_trait<Item = (#msg_type, ::error_enum::LabelVec1<#span_type, #msg_type>, ::error_enum::AdditionalKind)>> {
-                    match self {
+                    match #scrutinee {
                         #(#additional)*
                     }
                 }
+                #severity_level
+                #suggestion
+                fn message_template(&self) -> &'static ::core::primitive::str {
+                    match #scrutinee {
+                        #(#message_template)*
+                    }
+                }
+                fn primary_label_template(&self) -> &'static ::core::primitive::str {
+                    match #scrutinee {
+                        #(#primary_label_template)*
+                    }
+                }
+                fn primary_message_static(&self) -> ::core::option::Option<&'static ::core::primitive::str> {
+                    match #scrutinee {
+                        #(#static_messages)*
+                    }
+                }
+                fn kinds_and_numbers() -> ::error_enum::Vec<(Self::Kind, ::error_enum::String)> {
+                    #[allow(unused_mut)]
+                    let mut all = ::error_enum::Vec::new();
+                    #(#kinds_and_numbers)*
+                    all
+                }
+                #all_codes
             }
         });
 
@@ -35,6 +35,17 @@ fn basic() {
                 }
             }
             impl ::core::error::Error for FileSystemError {}
+            impl FileSystemError {
+                /// Codes of all variants, in declaration order.
+                const ALL_CODES: &'static [&'static ::core::primitive::str] = &["E01"];
+                /// Get the unit variant with the given code, or `None` if there is none.
+                fn from_code(code: &::core::primitive::str) -> ::core::option::Option<Self> {
+                    #[allow(unreachable_patterns)]
+                    match code {
+                        _ => ::core::option::Option::None,
+                    }
+                }
+            }
             impl ::error_enum::ErrorType for FileSystemError {
                 type Span = ::error_enum::SimpleSpan;
                 type Kind = ::error_enum::Kind;
@@ -64,6 +75,11 @@ fn basic() {
                 fn primary_message(&self) -> ::error_enum::String {
                     ::error_enum::format!("{self}")
                 }
+                fn primary_message_verbose(&self) -> ::error_enum::String {
+                    match self {
+                        Self::FileNotFound { .. } => ::error_enum::format!("{self}"),
+                    }
+                }
                 fn primary_labels(
                     &self,
                 ) -> ::error_enum::LabelVec1<::error_enum::SimpleSpan, ::error_enum::String> {
@@ -91,6 +107,27 @@ fn basic() {
                         Self::FileNotFound { path } => ::error_enum::Box::new([].into_iter()),
                     }
                 }
+                fn message_template(&self) -> &'static ::core::primitive::str {
+                    match self {
+                        Self::FileNotFound { .. } => "{path} not found.",
+                    }
+                }
+                fn primary_label_template(&self) -> &'static ::core::primitive::str {
+                    match self {
+                        Self::FileNotFound { .. } => "{path} not found.",
+                    }
+                }
+                fn primary_message_static(&self) -> ::core::option::Option<&'static ::core::primitive::str> {
+                    match self {
+                        Self::FileNotFound { .. } => ::core::option::Option::None,
+                    }
+                }
+                fn kinds_and_numbers() -> ::error_enum::Vec<(Self::Kind, ::error_enum::String)> {
+                    #[allow(unused_mut)]
+                    let mut all = ::error_enum::Vec::new();
+                    all.push((::error_enum::Kind::Error, ::error_enum::String::from("01")));
+                    all
+                }
             }
         },
     );
@@ -133,6 +170,18 @@ fn deep() {
                 }
             }
             impl ::core::error::Error for FileSystemError {}
+            impl FileSystemError {
+                /// Codes of all variants, in declaration order.
+                const ALL_CODES: &'static [&'static ::core::primitive::str] = &["E00"];
+                /// Get the unit variant with the given code, or `None` if there is none.
+                fn from_code(code: &::core::primitive::str) -> ::core::option::Option<Self> {
+                    #[allow(unreachable_patterns)]
+                    match code {
+                        "E00" => ::core::option::Option::Some(Self::AccessDenied),
+                        _ => ::core::option::Option::None,
+                    }
+                }
+            }
             impl ::error_enum::ErrorType for FileSystemError {
                 type Span = ::error_enum::SimpleSpan;
                 type Kind = ::error_enum::Kind;
@@ -161,6 +210,11 @@ fn deep() {
                 fn primary_message(&self) -> ::error_enum::String {
                     ::error_enum::format!("{self}")
                 }
+                fn primary_message_verbose(&self) -> ::error_enum::String {
+                    match self {
+                        Self::AccessDenied => ::error_enum::format!("{self}"),
+                    }
+                }
                 fn primary_labels(
                     &self,
                 ) -> ::error_enum::LabelVec1<::error_enum::SimpleSpan, ::error_enum::String> {
@@ -186,6 +240,27 @@ fn deep() {
                         Self::AccessDenied => ::error_enum::Box::new([].into_iter()),
                     }
                 }
+                fn message_template(&self) -> &'static ::core::primitive::str {
+                    match self {
+                        Self::AccessDenied => "无权限。",
+                    }
+                }
+                fn primary_label_template(&self) -> &'static ::core::primitive::str {
+                    match self {
+                        Self::AccessDenied => "无权限。",
+                    }
+                }
+                fn primary_message_static(&self) -> ::core::option::Option<&'static ::core::primitive::str> {
+                    match self {
+                        Self::AccessDenied => ::core::option::Option::Some("无权限。"),
+                    }
+                }
+                fn kinds_and_numbers() -> ::error_enum::Vec<(Self::Kind, ::error_enum::String)> {
+                    #[allow(unused_mut)]
+                    let mut all = ::error_enum::Vec::new();
+                    all.push((::error_enum::Kind::Error, ::error_enum::String::from("00")));
+                    all
+                }
             }
         },
     );
@@ -224,6 +299,17 @@ fn escaped_braces_in_msg() {
                 }
             }
             impl ::core::error::Error for FileSystemError {}
+            impl FileSystemError {
+                /// Codes of all variants, in declaration order.
+                const ALL_CODES: &'static [&'static ::core::primitive::str] = &["E01"];
+                /// Get the unit variant with the given code, or `None` if there is none.
+                fn from_code(code: &::core::primitive::str) -> ::core::option::Option<Self> {
+                    #[allow(unreachable_patterns)]
+                    match code {
+                        _ => ::core::option::Option::None,
+                    }
+                }
+            }
             impl ::error_enum::ErrorType for FileSystemError {
                 type Span = ::error_enum::SimpleSpan;
                 type Kind = ::error_enum::Kind;
@@ -253,6 +339,11 @@ fn escaped_braces_in_msg() {
                 fn primary_message(&self) -> ::error_enum::String {
                     ::error_enum::format!("{self}")
                 }
+                fn primary_message_verbose(&self) -> ::error_enum::String {
+                    match self {
+                        Self::FileNotFound(..) => ::error_enum::format!("{self}"),
+                    }
+                }
                 fn primary_labels(
                     &self,
                 ) -> ::error_enum::LabelVec1<::error_enum::SimpleSpan, ::error_enum::String> {
@@ -278,6 +369,27 @@ fn escaped_braces_in_msg() {
                         Self::FileNotFound(_0) => ::error_enum::Box::new([].into_iter()),
                     }
                 }
+                fn message_template(&self) -> &'static ::core::primitive::str {
+                    match self {
+                        Self::FileNotFound(..) => "{{0}} not found.",
+                    }
+                }
+                fn primary_label_template(&self) -> &'static ::core::primitive::str {
+                    match self {
+                        Self::FileNotFound(..) => "{{0}} not found.",
+                    }
+                }
+                fn primary_message_static(&self) -> ::core::option::Option<&'static ::core::primitive::str> {
+                    match self {
+                        Self::FileNotFound(..) => ::core::option::Option::Some("{0} not found."),
+                    }
+                }
+                fn kinds_and_numbers() -> ::error_enum::Vec<(Self::Kind, ::error_enum::String)> {
+                    #[allow(unused_mut)]
+                    let mut all = ::error_enum::Vec::new();
+                    all.push((::error_enum::Kind::Error, ::error_enum::String::from("01")));
+                    all
+                }
             }
         },
     );
@@ -33,6 +33,17 @@ fn test_error_type_with_derive_input() {
                 }
             }
             impl ::core::error::Error for ReadIntError {}
+            impl ReadIntError {
+                /// Codes of all variants, in declaration order.
+                const ALL_CODES: &'static [&'static ::core::primitive::str] = &["E00", "E01"];
+                /// Get the unit variant with the given code, or `None` if there is none.
+                fn from_code(code: &::core::primitive::str) -> ::core::option::Option<Self> {
+                    #[allow(unreachable_patterns)]
+                    match code {
+                        _ => ::core::option::Option::None,
+                    }
+                }
+            }
             impl ::error_enum::ErrorType for ReadIntError {
                 type Span = ::error_enum::SimpleSpan;
                 type Kind = ::error_enum::Kind;
@@ -75,6 +86,12 @@ fn test_error_type_with_derive_input() {
                 fn primary_message(&self) -> ::error_enum::String {
                     ::error_enum::format!("{self}")
                 }
+                fn primary_message_verbose(&self) -> ::error_enum::String {
+                    match self {
+                        Self::ParseIntError(..) => ::error_enum::format!("{self}"),
+                        Self::IOError(..) => ::error_enum::format!("{self}"),
+                    }
+                }
                 fn primary_labels(
                     &self,
                 ) -> ::error_enum::LabelVec1<::error_enum::SimpleSpan, ::error_enum::String> {
@@ -115,6 +132,31 @@ fn test_error_type_with_derive_input() {
                         ),
                     }
                 }
+                fn message_template(&self) -> &'static ::core::primitive::str {
+                    match self {
+                        Self::ParseIntError(..) => "Failed to parse integer from string due to: {0}",
+                        Self::IOError(..) => "Failed to read string due to: {2}",
+                    }
+                }
+                fn primary_label_template(&self) -> &'static ::core::primitive::str {
+                    match self {
+                        Self::ParseIntError(..) => "Failed to parse integer from string due to: {0}",
+                        Self::IOError(..) => "Failed to read string due to: {2}",
+                    }
+                }
+                fn primary_message_static(&self) -> ::core::option::Option<&'static ::core::primitive::str> {
+                    match self {
+                        Self::ParseIntError(..) => ::core::option::Option::None,
+                        Self::IOError(..) => ::core::option::Option::None,
+                    }
+                }
+                fn kinds_and_numbers() -> ::error_enum::Vec<(Self::Kind, ::error_enum::String)> {
+                    #[allow(unused_mut)]
+                    let mut all = ::error_enum::Vec::new();
+                    all.push((<::error_enum::Kind as ::core::default::Default>::default(), ::error_enum::String::from("00")));
+                    all.push((<::error_enum::Kind as ::core::default::Default>::default(), ::error_enum::String::from("01")));
+                    all
+                }
             }
         },
     );
@@ -142,6 +184,10 @@ fn test_error_type_with_derive_input() {
                 }
             }
             impl<'a> ::core::error::Error for ReadIntError<'a> {}
+            impl<'a> ReadIntError<'a> {
+                /// Codes of all variants, in declaration order.
+                const ALL_CODES: &'static [&'static ::core::primitive::str] = &["E"];
+            }
             impl<'a> ::error_enum::ErrorType for ReadIntError<'a> {
                 type Span = ::error_enum::SimpleSpan;
                 type Kind = ::error_enum::Kind;
@@ -175,6 +221,11 @@ fn test_error_type_with_derive_input() {
                 fn primary_message(&self) -> ::error_enum::String {
                     ::error_enum::format!("{self}")
                 }
+                fn primary_message_verbose(&self) -> ::error_enum::String {
+                    match self {
+                        Self(..) => ::error_enum::format!("{self}"),
+                    }
+                }
                 fn primary_labels(
                     &self,
                 ) -> ::error_enum::LabelVec1<::error_enum::SimpleSpan, ::error_enum::String> {
@@ -228,6 +279,27 @@ fn test_error_type_with_derive_input() {
                         ),
                     }
                 }
+                fn message_template(&self) -> &'static ::core::primitive::str {
+                    match self {
+                        Self(..) => "Failed to read an integer due to: {1}",
+                    }
+                }
+                fn primary_label_template(&self) -> &'static ::core::primitive::str {
+                    match self {
+                        Self(..) => "Failed to read an integer due to: {1}",
+                    }
+                }
+                fn primary_message_static(&self) -> ::core::option::Option<&'static ::core::primitive::str> {
+                    match self {
+                        Self(..) => ::core::option::Option::None,
+                    }
+                }
+                fn kinds_and_numbers() -> ::error_enum::Vec<(Self::Kind, ::error_enum::String)> {
+                    #[allow(unused_mut)]
+                    let mut all = ::error_enum::Vec::new();
+                    all.push((<::error_enum::Kind as ::core::default::Default>::default(), ::error_enum::String::from("")));
+                    all
+                }
             }
         },
     );
@@ -258,6 +330,10 @@ fn test_error_type_with_derive_input() {
                 }
             }
             impl<'a> ::core::error::Error for ParseIntError<'a> {}
+            impl<'a> ParseIntError<'a> {
+                /// Codes of all variants, in declaration order.
+                const ALL_CODES: &'static [&'static ::core::primitive::str] = &["E"];
+            }
             impl<'a> ::error_enum::ErrorType for ParseIntError<'a> {
                 type Span = ::error_enum::SimpleSpan;
                 type Kind = ::error_enum::Kind;
@@ -295,6 +371,11 @@ fn test_error_type_with_derive_input() {
                 fn primary_message(&self) -> ::error_enum::String {
                     ::error_enum::format!("{self}")
                 }
+                fn primary_message_verbose(&self) -> ::error_enum::String {
+                    match self {
+                        Self { .. } => ::error_enum::format!("{self}"),
+                    }
+                }
                 fn primary_labels(
                     &self,
                 ) -> ::error_enum::LabelVec1<::error_enum::SimpleSpan, ::error_enum::String> {
@@ -350,6 +431,27 @@ fn test_error_type_with_derive_input() {
                         ),
                     }
                 }
+                fn message_template(&self) -> &'static ::core::primitive::str {
+                    match self {
+                        Self { .. } => "Failed to parse the string to an integer",
+                    }
+                }
+                fn primary_label_template(&self) -> &'static ::core::primitive::str {
+                    match self {
+                        Self { .. } => "Failed to parse the string to an integer",
+                    }
+                }
+                fn primary_message_static(&self) -> ::core::option::Option<&'static ::core::primitive::str> {
+                    match self {
+                        Self { .. } => ::core::option::Option::Some("Failed to parse the string to an integer"),
+                    }
+                }
+                fn kinds_and_numbers() -> ::error_enum::Vec<(Self::Kind, ::error_enum::String)> {
+                    #[allow(unused_mut)]
+                    let mut all = ::error_enum::Vec::new();
+                    all.push((<::error_enum::Kind as ::core::default::Default>::default(), ::error_enum::String::from("")));
+                    all
+                }
             }
         },
     );
@@ -407,6 +509,11 @@ fn custom_kind_type_and_expr() {
                 fn primary_message(&self) -> ::error_enum::String {
                     ::error_enum::format!("{self}")
                 }
+                fn primary_message_verbose(&self) -> ::error_enum::String {
+                    match self {
+                        Self => ::error_enum::format!("{self}"),
+                    }
+                }
                 fn primary_labels(
                     &self,
                 ) -> ::error_enum::LabelVec1<::error_enum::SimpleSpan, ::error_enum::String> {
@@ -432,6 +539,27 @@ fn custom_kind_type_and_expr() {
                         Self => ::error_enum::Box::new([].into_iter()),
                     }
                 }
+                fn message_template(&self) -> &'static ::core::primitive::str {
+                    match self {
+                        Self => "boom",
+                    }
+                }
+                fn primary_label_template(&self) -> &'static ::core::primitive::str {
+                    match self {
+                        Self => "boom",
+                    }
+                }
+                fn primary_message_static(&self) -> ::core::option::Option<&'static ::core::primitive::str> {
+                    match self {
+                        Self => ::core::option::Option::Some("boom"),
+                    }
+                }
+                fn kinds_and_numbers() -> ::error_enum::Vec<(Self::Kind, ::error_enum::String)> {
+                    #[allow(unused_mut)]
+                    let mut all = ::error_enum::Vec::new();
+                    all.push((MyKind::Bug, ::error_enum::String::from("01")));
+                    all
+                }
             }
         },
     );
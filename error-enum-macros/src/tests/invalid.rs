@@ -0,0 +1,661 @@
+use super::{test_error_type_derive_error, test_error_type_error, test_error_type_errors};
+use quote::quote;
+
+#[test]
+fn unlabeled_secondary_span() {
+    test_error_type_derive_error(
+        quote! {
+            enum FooError {
+                #[diag(kind = "error", number = "0", msg = "foo")]
+                Foo {
+                    #[diag(span)]
+                    a: SimpleSpan,
+                    #[diag(span)]
+                    b: SimpleSpan,
+                },
+            }
+        },
+        "only the first `#[diag(span)]` field is the primary span; label the others with `#[diag(span, label = \"...\")]`",
+    );
+}
+
+#[test]
+fn span_only_tuple_field_in_message() {
+    test_error_type_derive_error(
+        quote! {
+            enum FooError {
+                #[diag(kind = "error", number = "0", msg = "unexpected {0}")]
+                Foo(#[diag(span)] SimpleSpan),
+            }
+        },
+        "the message refers to `{0}`, the only field of this variant, which is its `#[diag(span)]`; spans are not displayed, so add a field for the text or remove `{0}` from the message",
+    );
+}
+
+#[test]
+fn arg_out_of_range() {
+    test_error_type_derive_error(
+        quote! {
+            enum FooError {
+                #[diag(kind = "error", number = "0", msg = "{path}")]
+                #[diag(arg(path = 1))]
+                Foo(String),
+            }
+        },
+        "tuple field index 1 is out of range for 1 field(s)",
+    );
+}
+
+#[test]
+fn arg_on_named_variant() {
+    test_error_type_derive_error(
+        quote! {
+            enum FooError {
+                #[diag(kind = "error", number = "0", msg = "{path}")]
+                #[diag(arg(path = 0))]
+                Foo { path: String },
+            }
+        },
+        "`#[diag(arg(...))]` is only valid on tuple variants",
+    );
+}
+
+#[test]
+fn struct_keyword() {
+    test_error_type_error(
+        quote! {
+            #[diag(kind = "error", number = "0", msg = "foo")]
+            pub struct FooError {
+                span: SimpleSpan,
+            }
+        },
+        "`error_type!` defines an enum and takes no `struct` keyword. For a single error struct, use `#[derive(ErrorType)]` instead",
+    );
+}
+
+#[test]
+fn struct_fields() {
+    test_error_type_error(
+        quote! {
+            #[diag(kind = "error", number = "0", msg = "foo")]
+            pub FooError {
+                span: SimpleSpan,
+            }
+        },
+        "expected a variant, found a field; `error_type!` lists variants. For a single error struct, use `#[derive(ErrorType)]` instead",
+    );
+}
+
+#[test]
+fn missing_message() {
+    test_error_type_error(
+        quote! {
+            FooError {
+                #[diag(kind = "error", number = "0")]
+                Foo,
+            }
+        },
+        "Missing message. Consider using `#[diag(msg = \"...\")]` on the variant, a prefix, or the root as a fallback",
+    );
+}
+
+#[test]
+fn infer_span_on_variant() {
+    test_error_type_error(
+        quote! {
+            FooError {
+                #[diag(kind = "error", msg = "foo", infer_span)]
+                Foo(SimpleSpan),
+            }
+        },
+        "`#[diag(infer_span)]` is only valid on the enum itself",
+    );
+}
+
+#[test]
+fn enforce_period() {
+    test_error_type_error(
+        quote! {
+            #[diag(enforce_period)]
+            FooError {
+                #[diag(kind = "error")]
+                {
+                    #[diag(number = "0", msg = "file not found.")]
+                    Foo,
+                    #[diag(number = "1", msg = "access denied")]
+                    Bar,
+                },
+            }
+        },
+        "message must end with `.`, `!` or `?` (required by `#[diag(enforce_period)]`)",
+    );
+}
+
+#[test]
+fn enforce_period_on_prefix() {
+    test_error_type_error(
+        quote! {
+            FooError {
+                #[diag(enforce_period)]
+                {
+                    #[diag(kind = "error", msg = "foo.")]
+                    Foo,
+                },
+            }
+        },
+        "`#[diag(enforce_period)]` is only valid on the enum itself",
+    );
+}
+
+#[test]
+fn suggest_without_span() {
+    test_error_type_error(
+        quote! {
+            FooError {
+                #[diag(kind = "error", number = "0", msg = "unknown keyword")]
+                #[diag(suggest = "fn")]
+                Foo { found: String },
+            }
+        },
+        "`#[diag(suggest = \"...\")]` requires a `#[diag(span)]` field to replace",
+    );
+}
+
+#[test]
+fn suggest_on_prefix() {
+    test_error_type_error(
+        quote! {
+            FooError {
+                #[diag(suggest = "fn")]
+                {
+                    #[diag(kind = "error", msg = "foo")]
+                    Foo(#[diag(span)] SimpleSpan),
+                },
+            }
+        },
+        "`#[diag(suggest = \"...\")]` is only valid on variants",
+    );
+}
+
+#[test]
+fn unknown_msg_ref() {
+    test_error_type_error(
+        quote! {
+            #[diag(templates(internal = "internal error: {0}"))]
+            FooError {
+                #[diag(kind = "error", number = "0", msg_ref = "intern")]
+                Foo(String),
+            }
+        },
+        "unknown message template `intern`; declare it with `#[diag(templates(intern = \"...\"))]` on the enum",
+    );
+}
+
+#[test]
+fn templates_on_prefix() {
+    test_error_type_error(
+        quote! {
+            FooError {
+                #[diag(templates(internal = "internal error: {0}"))]
+                {
+                    #[diag(kind = "error", msg_ref = "internal")]
+                    Foo(String),
+                },
+            }
+        },
+        "`#[diag(templates(...))]` is only valid on the enum itself",
+    );
+}
+
+#[test]
+fn code_enum_on_prefix() {
+    test_error_type_error(
+        quote! {
+            FooError {
+                #[diag(code_enum)]
+                {
+                    #[diag(kind = "error", msg = "foo")]
+                    Foo,
+                },
+            }
+        },
+        "`#[diag(code_enum)]` is only valid on the enum itself",
+    );
+}
+
+#[test]
+fn code_enum_on_struct() {
+    test_error_type_derive_error(
+        quote! {
+            #[diag(code_enum)]
+            #[diag(kind = "error", msg = "foo")]
+            struct FooError;
+        },
+        "`#[diag(code_enum)]` is only valid on enums",
+    );
+}
+
+#[test]
+fn level_out_of_range() {
+    test_error_type_error(
+        quote! {
+            FooError {
+                #[diag(kind = "error", number = "0", msg = "foo", level = 4)]
+                Foo,
+            }
+        },
+        "`level` must be 0 (error), 1 (warning), 2 (note) or 3 (none)",
+    );
+}
+
+#[test]
+fn contiguous_numbers_gap() {
+    test_error_type_error(
+        quote! {
+            #[diag(contiguous_numbers)]
+            FooError {
+                #[diag(kind = "error", msg = "foo")]
+                {
+                    #[diag(number = "0")]
+                    Foo,
+                    #[diag(number = "1")]
+                    Bar,
+                    #[diag(number = "3")]
+                    Baz,
+                },
+            }
+        },
+        "number `3` leaves a gap after `1`; expected `2`",
+    );
+}
+
+#[test]
+fn contiguous_numbers_first_gap() {
+    test_error_type_error(
+        quote! {
+            #[diag(contiguous_numbers)]
+            FooError {
+                #[diag(kind = "error", msg = "foo", number = "2")]
+                Foo,
+            }
+        },
+        "number `2` leaves a gap; the first number should be `0` or `1`",
+    );
+}
+
+#[test]
+fn contiguous_numbers_not_decimal() {
+    test_error_type_error(
+        quote! {
+            #[diag(contiguous_numbers)]
+            FooError {
+                #[diag(kind = "error", msg = "foo", number = "x")]
+                Foo,
+            }
+        },
+        "`#[diag(contiguous_numbers)]` requires decimal numbers, found `x`",
+    );
+}
+
+#[test]
+fn contiguous_numbers_on_prefix() {
+    test_error_type_error(
+        quote! {
+            FooError {
+                #[diag(contiguous_numbers)]
+                {
+                    #[diag(kind = "error", msg = "foo")]
+                    Foo,
+                },
+            }
+        },
+        "`#[diag(contiguous_numbers)]` is only valid on the enum itself",
+    );
+}
+
+#[test]
+fn doc_placeholder_without_doc() {
+    test_error_type_error(
+        quote! {
+            FooError {
+                #[diag(kind = "error", number = "0", msg = "{doc} failed")]
+                Foo,
+            }
+        },
+        "`{doc}` requires a doc comment on the variant",
+    );
+}
+
+#[test]
+fn from_without_field() {
+    test_error_type_error(
+        quote! {
+            FooError {
+                #[diag(kind = "error", number = "0", msg = "foo", from)]
+                Foo,
+            }
+        },
+        "`#[diag(from)]` requires exactly one field, found 0",
+    );
+}
+
+#[test]
+fn from_with_two_fields() {
+    test_error_type_error(
+        quote! {
+            FooError {
+                #[diag(kind = "error", number = "0", msg = "foo", from)]
+                Foo(u8, u16),
+            }
+        },
+        "`#[diag(from)]` requires exactly one field, found 2",
+    );
+}
+
+#[test]
+fn from_on_prefix() {
+    test_error_type_error(
+        quote! {
+            FooError {
+                #[diag(from)]
+                {
+                    #[diag(kind = "error", number = "0", msg = "foo")]
+                    Foo(u8),
+                },
+            }
+        },
+        "`#[diag(from)]` is only valid on variants",
+    );
+}
+
+#[test]
+fn clone_on_derive() {
+    test_error_type_derive_error(
+        quote! {
+            #[diag(clone)]
+            enum FooError {
+                #[diag(kind = "error", number = "0", msg = "foo")]
+                Foo,
+            }
+        },
+        "`#[diag(clone)]` is only valid in `error_type!`; add `#[derive(Clone)]` instead",
+    );
+}
+
+#[test]
+fn clone_on_prefix() {
+    test_error_type_error(
+        quote! {
+            FooError {
+                #[diag(clone)]
+                {
+                    #[diag(kind = "error", number = "0", msg = "foo")]
+                    Foo,
+                },
+            }
+        },
+        "`#[diag(clone)]` is only valid on the enum itself",
+    );
+}
+
+#[test]
+fn two_error_sources() {
+    test_error_type_error(
+        quote! {
+            FooError {
+                #[diag(kind = "error", number = "0", msg = "foo")]
+                Foo(#[diag(source)] Error, #[diag(source)] Error),
+            }
+        },
+        "only one field may be `#[diag(source)]`",
+    );
+}
+
+#[test]
+fn error_source_on_nested() {
+    test_error_type_error(
+        quote! {
+            FooError {
+                #[diag(number = "0", nested)]
+                Foo(#[diag(source)] Inner),
+            }
+        },
+        "`#[diag(source)]` is implied by `#[diag(nested)]`",
+    );
+}
+
+#[test]
+fn separator_without_code_base() {
+    test_error_type_error(
+        quote! {
+            #[diag(separator = ".")]
+            FooError {
+                #[diag(kind = "error", number = "0", msg = "foo")]
+                Foo,
+            }
+        },
+        "`#[diag(separator = \"...\")]` requires `#[diag(code_base = \"...\")]`",
+    );
+}
+
+#[test]
+fn code_base_on_prefix() {
+    test_error_type_error(
+        quote! {
+            FooError {
+                #[diag(code_base = "FOO")]
+                {
+                    #[diag(kind = "error", number = "0", msg = "foo")]
+                    Foo,
+                },
+            }
+        },
+        "`#[diag(code_base)]` is only valid on the enum itself",
+    );
+}
+
+#[test]
+fn kind_prefix_without_code_base() {
+    test_error_type_error(
+        quote! {
+            #[diag(kind_prefix)]
+            FooError {
+                #[diag(kind = "error", number = "0", msg = "foo")]
+                Foo,
+            }
+        },
+        "`#[diag(kind_prefix)]` requires `#[diag(code_base = \"...\")]`",
+    );
+}
+
+#[test]
+fn kind_prefix_on_variant() {
+    test_error_type_error(
+        quote! {
+            #[diag(code_base = "FOO")]
+            FooError {
+                #[diag(kind = "error", number = "0", msg = "foo", kind_prefix)]
+                Foo,
+            }
+        },
+        "`#[diag(kind_prefix)]` is only valid on the enum itself",
+    );
+}
+
+#[test]
+fn unknown_named_field_in_msg() {
+    test_error_type_error(
+        quote! {
+            FooError {
+                #[diag(kind = "error", number = "0", msg = "{path} not found")]
+                Foo { file: String },
+            }
+        },
+        "`path` in the message is not a field of this variant",
+    );
+}
+
+#[test]
+fn unknown_width_field_in_msg() {
+    test_error_type_error(
+        quote! {
+            FooError {
+                #[diag(kind = "error", number = "0", msg = "{file:>width$}")]
+                Foo { file: String },
+            }
+        },
+        "`width` in the message is not a field of this variant",
+    );
+}
+
+#[test]
+fn named_field_in_unit_msg() {
+    test_error_type_derive_error(
+        quote! {
+            enum FooError {
+                #[diag(kind = "error", number = "0", msg = "{{literal}} and {name:?}")]
+                Foo,
+            }
+        },
+        "`name` in the message is not a field of this variant",
+    );
+}
+
+#[test]
+fn named_field_in_tuple_msg() {
+    test_error_type_error(
+        quote! {
+            FooError {
+                #[diag(kind = "error", number = "0", msg = "{path} not found")]
+                Foo(String),
+            }
+        },
+        "`path` in the message is not a field of this variant",
+    );
+}
+
+#[test]
+fn placeholder_on_prefix() {
+    test_error_type_error(
+        quote! {
+            FooError {
+                #[diag(placeholder = "double_brace")]
+                {
+                    #[diag(kind = "error", msg = "foo")]
+                    Foo,
+                },
+            }
+        },
+        "`#[diag(placeholder = \"...\")]` is only valid on the enum itself",
+    );
+}
+
+#[test]
+fn unknown_placeholder() {
+    test_error_type_error(
+        quote! {
+            #[diag(placeholder = "percent")]
+            FooError {
+                #[diag(kind = "error", msg = "foo")]
+                Foo,
+            }
+        },
+        "expected `\"rust\"` or `\"double_brace\"`",
+    );
+}
+
+#[test]
+fn number_format_on_prefix() {
+    test_error_type_error(
+        quote! {
+            FooError {
+                #[diag(kind = "error", number_format = "{:02}")]
+                {
+                    #[diag(msg = "foo")]
+                    Foo,
+                },
+            }
+        },
+        "`#[diag(number_format = \"...\")]` is only valid on the enum itself",
+    );
+}
+
+#[test]
+fn number_format_without_placeholder() {
+    test_error_type_error(
+        quote! {
+            #[diag(number_format = "{:x}")]
+            FooError {
+                #[diag(kind = "error", msg = "foo")]
+                Foo,
+            }
+        },
+        "expected exactly one `{}` or `{:0N}` placeholder in `number_format`",
+    );
+}
+
+#[test]
+fn unnamed_field_out_of_range() {
+    test_error_type_error(
+        quote! {
+            FooError {
+                #[diag(kind = "error", number = "0", msg = "{0} and {5}")]
+                Foo(u32, u32),
+            }
+        },
+        "tuple field index 5 in the message is out of range for 2 field(s)",
+    );
+}
+
+#[test]
+fn binding_field_out_of_range() {
+    test_error_type_error(
+        quote! {
+            FooError {
+                #[diag(kind = "error", number = "0", msg = "{_0} and {_2:?}")]
+                Foo(u32, u32),
+            }
+        },
+        "tuple field index 2 in the message is out of range for 2 field(s)",
+    );
+}
+
+#[test]
+fn multiple_errors() {
+    test_error_type_errors(
+        quote! {
+            FooError {
+                #[diag(kind = "error")]
+                {
+                    #[diag(number = "0")]
+                    Foo,
+                    #[diag(number = "1", msg = "bar")]
+                    Bar,
+                    #[diag(number = "2")]
+                    Baz,
+                    #[diag(number = "3", msg = "{0} and {5}")]
+                    Qux(u32, u32),
+                },
+            }
+        },
+        &[
+            "Missing message. Consider using `#[diag(msg = \"...\")]` on the variant, a prefix, or the root as a fallback",
+            "Missing message. Consider using `#[diag(msg = \"...\")]` on the variant, a prefix, or the root as a fallback",
+            "tuple field index 5 in the message is out of range for 2 field(s)",
+        ],
+    );
+}
+
+#[test]
+fn code_placeholder_with_custom_kind() {
+    test_error_type_error(
+        quote! {
+            #[diag(kind_type = "MyKind")]
+            FooError {
+                #[diag(kind = MyKind::Bug, number = "0", msg = "{code}: foo")]
+                Foo,
+            }
+        },
+        "`{code}` requires a built-in `kind`; the code of a custom kind is only known at runtime",
+    );
+}
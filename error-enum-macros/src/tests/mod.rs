@@ -57,6 +57,36 @@ fn test_error_type_derive(tokens: TokenStream, expected: TokenStream) {
     assert_eq_source(&output, &expected);
 }
 
+#[track_caller]
+fn test_error_type_error(tokens: TokenStream, expected: &str) {
+    let err = match syn::parse2::<ErrorEnum>(tokens) {
+        Ok(input) => input.try_to_tokens(&mut TokenStream::new()).unwrap_err(),
+        Err(err) => err,
+    };
+    assert_eq!(err.to_string(), expected);
+}
+
+#[track_caller]
+fn test_error_type_errors(tokens: TokenStream, expected: &[&str]) {
+    let err = match syn::parse2::<ErrorEnum>(tokens) {
+        Ok(input) => input.try_to_tokens(&mut TokenStream::new()).unwrap_err(),
+        Err(err) => err,
+    };
+    let messages: Vec<_> = err.into_iter().map(|err| err.to_string()).collect();
+    assert_eq!(messages, expected);
+}
+
+#[track_caller]
+fn test_error_type_derive_error(tokens: TokenStream, expected: &str) {
+    let input: DeriveInput = syn::parse2(tokens).unwrap();
+    let err = match ErrorEnum::try_from(input) {
+        Ok(input) => input.try_to_tokens(&mut TokenStream::new()).unwrap_err(),
+        Err(err) => err,
+    };
+    assert_eq!(err.to_string(), expected);
+}
+
 mod basic;
 mod derive;
+mod invalid;
 mod nested;
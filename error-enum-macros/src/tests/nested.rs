@@ -33,7 +33,15 @@ fn nested() {
                     }
                 }
             }
-            impl ::core::error::Error for FileSystemError {}
+            impl ::core::error::Error for FileSystemError {
+                fn source(&self) -> ::core::option::Option<&(dyn ::core::error::Error + 'static)> {
+                    #[allow(unreachable_patterns)]
+                    match self {
+                        Self::FileError(inner) => ::core::error::Error::source(inner),
+                        _ => ::core::option::Option::None,
+                    }
+                }
+            }
             impl ::error_enum::ErrorType for FileSystemError {
                 type Span = ::error_enum::SimpleSpan;
                 type Kind = ::error_enum::Kind;
@@ -83,6 +91,11 @@ fn nested() {
                 fn primary_message(&self) -> ::error_enum::String {
                     ::error_enum::format!("{self}")
                 }
+                fn primary_message_verbose(&self) -> ::error_enum::String {
+                    match self {
+                        Self::FileError(inner) => ::error_enum::ErrorType::primary_message_verbose(inner),
+                    }
+                }
                 fn primary_labels(
                     &self,
                 ) -> ::error_enum::LabelVec1<::error_enum::SimpleSpan, ::error_enum::String> {
@@ -105,6 +118,49 @@ fn nested() {
                         Self::FileError(inner) => ::error_enum::ErrorType::additional(inner),
                     }
                 }
+                fn severity_level(&self) -> ::core::primitive::u8 {
+                    #[allow(unreachable_patterns)]
+                    match self {
+                        Self::FileError(inner) => ::error_enum::ErrorType::severity_level(inner),
+                        _ => ::error_enum::DiagnosticKind::severity_level(
+                            &::error_enum::ErrorType::kind(self),
+                        ),
+                    }
+                }
+                fn suggestion(
+                    &self,
+                ) -> ::core::option::Option<(Self::Span, ::error_enum::String)> {
+                    #[allow(unreachable_patterns)]
+                    match self {
+                        Self::FileError(inner) => ::error_enum::ErrorType::suggestion(inner),
+                        _ => ::core::option::Option::None,
+                    }
+                }
+                fn message_template(&self) -> &'static ::core::primitive::str {
+                    match self {
+                        Self::FileError(inner) => ::error_enum::ErrorType::message_template(inner),
+                    }
+                }
+                fn primary_label_template(&self) -> &'static ::core::primitive::str {
+                    match self {
+                        Self::FileError(inner) => ::error_enum::ErrorType::primary_label_template(inner),
+                    }
+                }
+                fn primary_message_static(&self) -> ::core::option::Option<&'static ::core::primitive::str> {
+                    match self {
+                        Self::FileError(inner) => ::error_enum::ErrorType::primary_message_static(inner),
+                    }
+                }
+                fn kinds_and_numbers() -> ::error_enum::Vec<(Self::Kind, ::error_enum::String)> {
+                    #[allow(unused_mut)]
+                    let mut all = ::error_enum::Vec::new();
+                    all.extend(
+                        <FileError as ::error_enum::ErrorType>::kinds_and_numbers()
+                            .into_iter()
+                            .map(|(kind, number)| (kind, ::error_enum::format!("{}{}", "01", number))),
+                    );
+                    all
+                }
             }
         },
     );
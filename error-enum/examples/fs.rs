@@ -13,13 +13,13 @@ error_type! {
             #[diag(msg = "File Kind-Related Errors.")]
             {
                 #[diag(number = "0")]
-                #[diag(msg = "File {path:?} Not Found")]
+                #[diag(msg = "File {path:disp} Not Found")]
                 FileNotFound {
                     /// File path
                     path: PathBuf
                 },
                 #[diag(number = "1")]
-                #[diag(msg = "Path {0:?} does not point to a file.")]
+                #[diag(msg = "Path {0:disp} does not point to a file.")]
                 NotAFile (PathBuf),
             },
             #[diag(number = "1")]
@@ -62,12 +62,12 @@ fn main() {
         &FileSystemError::FileNotFound {
             path: "fs.rs".into(),
         },
-        "File \"fs.rs\" Not Found",
+        "File fs.rs Not Found",
         "E00",
     );
     test_error(
         &FileSystemError::NotAFile("target".into()),
-        "Path \"target\" does not point to a file.",
+        "Path target does not point to a file.",
         "E01",
     );
     test_error(&FileSystemError::AccessDenied, "Access Denied.", "E11");
@@ -8,20 +8,21 @@ use std::{io, path::PathBuf};
 #[derive(Debug, ErrorType)]
 enum ReadIntError {
     #[diag(msg = "Failed to parse integer from string due to: {0}")]
-    ParseInt(std::num::ParseIntError),
+    ParseInt(#[diag(source)] std::num::ParseIntError),
     #[diag(msg = "Failed to read string due to: {0}")]
-    IO(io::Error),
+    IO(#[diag(source)] io::Error),
     #[diag(msg = "{error}")]
     #[diag(help("canonicalizing {path:?}"))]
     Canonicalize {
         path: PathBuf,
+        #[diag(source)]
         error: std::io::Error,
     },
 }
 
 #[derive(Debug, ErrorType)]
 #[diag(msg = "Failed to read string due to: {0}")]
-struct IOError(io::Error);
+struct IOError(#[diag(source)] io::Error);
 
 fn main() {
     let parse_error = ReadIntError::ParseInt("abc".parse::<i32>().unwrap_err());
@@ -32,10 +33,15 @@ fn main() {
 
     let simple_io_error = IOError(io::Error::new(io::ErrorKind::NotFound, "file not found"));
     println!("Simple IOError: {}", simple_io_error);
+    println!("Simple IOError code: {}", simple_io_error.code());
 
     let canonicalize_error = ReadIntError::Canonicalize {
         path: PathBuf::from("path/to/file"),
         error: io::Error::new(io::ErrorKind::NotFound, "file not found"),
     };
     println!("CanonicalizeError: {}", canonicalize_error);
+    println!(
+        "CanonicalizeError source: {}",
+        std::error::Error::source(&canonicalize_error).unwrap()
+    );
 }
@@ -18,9 +18,47 @@
 //! ```rust
 #![doc = include_str!("../examples/wrapper.rs")]
 //! ```
+//!
+//! ## Displaying Paths
+//!
+//! [`Path`](std::path::Path) has no [`Display`](core::fmt::Display) implementation, so `{path}`
+//! in a message does not compile:
+//!
+//! ```compile_fail
+//! error_enum::error_type! {
+//!     #[derive(Debug)]
+//!     pub FsError {
+//!         #[diag(kind = "Error", number = "0", msg = "{path} not found")]
+//!         NotFound { path: std::path::PathBuf },
+//!     }
+//! }
+//! ```
+//!
+//! Write `{path:disp}` instead, which expands to `path.display()` and renders the path lossily
+//! without the quotes of `{path:?}`.
 
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use error_enum_core::hyperlinks_enabled;
+#[cfg(feature = "testing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub use error_enum_core::testing;
+#[cfg(feature = "ariadne")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ariadne")))]
+pub use error_enum_core::AriadneSpan;
+#[cfg(feature = "proc-macro2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "proc-macro2")))]
+pub use error_enum_core::ProcMacroSpan;
+#[cfg(feature = "owo-colors")]
+#[cfg_attr(docsrs, doc(cfg(feature = "owo-colors")))]
+pub use error_enum_core::StyleSheet;
 pub use error_enum_core::{
-    format, vec1, AdditionalKind, Box, Cow, DiagnosticKind, ErrorType, ErrorTypeExt, Indexer, Kind,
-    LabelVec1, LineIndexer, SimpleSpan, Span, SpannedLabel, String, Vec1,
+    format, sort_by_code, vec1, AdditionalKind, Annotated, Box, CachedRender, Cow, DiagnosticKind,
+    Diagnostics, DynErrorType, ErrorType, ErrorTypeExt, Indexer, Kind, LabelVec1, LineIndexer,
+    OutputFormat, OwnedSpan, RemapIndexer, RenderOptions, Rendered, SimpleSpan, SimpleSpanRc,
+    SourceMap, Span, SpannedLabel, String, Vec, Vec1,
 };
+#[cfg(feature = "memmap2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "memmap2")))]
+pub use error_enum_core::{MmapSource, MmapSpan};
 pub use error_enum_macros::{error_type, ErrorType};
@@ -0,0 +1,87 @@
+//! Tests for notes attached at runtime.
+
+#![allow(clippy::unwrap_used)]
+
+use error_enum::{error_type, ErrorType, ErrorTypeExt, SimpleSpan};
+
+error_type! {
+    #[derive(Debug)]
+    pub ResolveError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "cannot find `{name}`")]
+            #[diag(label = "not found in this scope")]
+            #[diag(help("check the imports"))]
+            Unresolved {
+                /// The unresolved name.
+                name: String,
+                /// Span of the name.
+                #[diag(span)]
+                span: SimpleSpan,
+            },
+        },
+    }
+}
+
+fn unresolved() -> ResolveError {
+    ResolveError::Unresolved {
+        name: "fob".into(),
+        span: SimpleSpan::new("main.rs", "let x = fob;", 8, 11),
+    }
+}
+
+#[test]
+fn runtime_notes() {
+    let error = unresolved();
+    let annotated = error
+        .with_note("did you mean `foo`?")
+        .with_note("`foo` is defined in `lib.rs`");
+    assert_eq!(annotated.to_string(), error.to_string());
+    assert_eq!(annotated.code(), "E0");
+
+    let additional: Vec<_> = annotated
+        .additional()
+        .map(|(message, _, kind)| (message, kind))
+        .collect();
+    assert_eq!(
+        additional,
+        [
+            ("check the imports".into(), error_enum::AdditionalKind::Help),
+            (
+                "did you mean `foo`?".into(),
+                error_enum::AdditionalKind::Note
+            ),
+            (
+                "`foo` is defined in `lib.rs`".into(),
+                error_enum::AdditionalKind::Note
+            ),
+        ],
+    );
+}
+
+#[test]
+#[cfg(feature = "codespan-reporting")]
+fn codespan_reporting() {
+    use codespan_reporting::term::Config;
+
+    let error = unresolved();
+    let s = error
+        .with_note("did you mean `foo`?")
+        .fmt_as_codespan_diagnostic_with(Config::default(), None)
+        .unwrap();
+    assert_eq!(
+        s,
+        "\
+error[E0]: cannot find `fob`
+  ┌─ main.rs:1:9
+  │
+1 │ let x = fob;
+  │         ^^^ not found in this scope
+  │
+  = check the imports
+  = did you mean `foo`?
+
+",
+    );
+}
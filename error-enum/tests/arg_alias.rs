@@ -0,0 +1,40 @@
+//! Tests for named aliases of tuple fields.
+
+#![allow(clippy::unwrap_used)]
+
+use error_enum::{error_type, ErrorType};
+
+error_type! {
+    #[derive(Debug)]
+    pub IoError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "0")]
+            #[diag(arg(path = 0, reason = 1))]
+            #[diag(msg = "cannot read `{path}`: {reason}")]
+            #[diag(label = "while reading {0:?}")]
+            #[diag(note("check that `{path}` exists"))]
+            Read(String, String),
+            #[diag(number = "1")]
+            #[diag(msg = "cannot write `{_0}`: {_1}")]
+            #[diag(label = "while writing {_0:?}")]
+            Write(String, String),
+        },
+    }
+}
+
+#[test]
+fn tuple_field_alias() {
+    let error = IoError::Read("a.txt".into(), "not found".into());
+    assert_eq!(error.to_string(), "cannot read `a.txt`: not found");
+    assert_eq!(error.primary_labels().first().1, "while reading \"a.txt\"");
+    let (note, _, _) = error.additional().next().unwrap();
+    assert_eq!(note, "check that `a.txt` exists");
+}
+
+#[test]
+fn tuple_field_binding_name() {
+    let error = IoError::Write("a.txt".into(), "read-only".into());
+    assert_eq!(error.to_string(), "cannot write `a.txt`: read-only");
+    assert_eq!(error.primary_labels().first().1, "while writing \"a.txt\"");
+}
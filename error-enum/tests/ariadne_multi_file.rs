@@ -0,0 +1,60 @@
+//! Tests for ariadne reports with labels in several files.
+
+#![cfg(feature = "ariadne")]
+#![allow(clippy::unwrap_used)]
+
+use error_enum::{error_type, ErrorTypeExt, SimpleSpan};
+
+error_type! {
+    #[derive(Debug)]
+    pub LinkError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "0", msg = "`{name}` is defined twice")]
+            Duplicate {
+                /// Name of the symbol.
+                name: String,
+                /// The second definition.
+                #[diag(span, label = "redefined here")]
+                second: SimpleSpan,
+                /// The first definition.
+                #[diag(span, label = "first defined here")]
+                first: SimpleSpan,
+            },
+        },
+    }
+}
+
+const A: &str = "fn main() {}\n";
+const B: &str = "pub fn helper() {}\nfn main() {}\n";
+
+#[test]
+fn ariadne_multi_file() {
+    let error = LinkError::Duplicate {
+        name: "main".into(),
+        second: SimpleSpan::new("b.rs", B, 22, 26),
+        first: SimpleSpan::new("a.rs", A, 3, 7),
+    };
+    let s = error
+        .fmt_as_ariadne_report_with(ariadne::Config::new().with_color(false))
+        .unwrap();
+    let lines: Vec<_> = s.lines().map(str::trim_end).collect();
+    assert_eq!(
+        lines,
+        [
+            "[E0] Error: `main` is defined twice",
+            "   ╭─[ b.rs:2:4 ]",
+            "   │",
+            " 2 │ fn main() {}",
+            "   │    ──┬─",
+            "   │      ╰─── redefined here",
+            "   │",
+            "   ├─[ a.rs:1:4 ]",
+            "   │",
+            " 1 │ fn main() {}",
+            "   │    ──┬─",
+            "   │      ╰─── first defined here",
+            "───╯",
+        ],
+    );
+}
@@ -0,0 +1,34 @@
+//! Tests for building custom ariadne reports from our spans.
+
+#![cfg(feature = "ariadne")]
+#![allow(clippy::unwrap_used)]
+
+use ariadne::{Config, Label, Report, ReportKind};
+use error_enum::{AriadneSpan, SimpleSpan, Span};
+
+#[test]
+fn custom_ariadne_report() {
+    let source = "let x = 1 $ 2;";
+    let span = SimpleSpan::new("main.rs", source, 10, 11);
+    let adapter = AriadneSpan::new(span.clone());
+    assert_eq!(ariadne::Span::source(&adapter).as_ref(), "main.rs");
+    assert_eq!(ariadne::Span::start(&adapter), 10);
+    assert_eq!(ariadne::Span::end(&adapter), 11);
+    let label = Label::new(adapter).with_message("not an operator");
+
+    let mut output = Vec::new();
+    Report::build(ReportKind::Error, AriadneSpan::from(span.clone()))
+        .with_message("unexpected character")
+        .with_label(label)
+        .with_config(Config::default().with_color(false))
+        .finish()
+        .write(
+            ariadne::sources([(span.uri().clone(), source)]),
+            &mut output,
+        )
+        .unwrap();
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains("main.rs:1:11"), "{output}");
+    assert!(output.contains("not an operator"), "{output}");
+    assert_eq!(AriadneSpan::new(span.clone()).into_inner(), span);
+}
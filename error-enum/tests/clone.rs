@@ -0,0 +1,39 @@
+//! Tests for `#[diag(clone)]`.
+
+use error_enum::{error_type, ErrorType, SimpleSpan};
+
+error_type! {
+    #[derive(Debug, PartialEq)]
+    #[diag(clone)]
+    RetryError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "01")]
+            #[diag(msg = "request to {0} timed out")]
+            Timeout(String),
+            #[diag(number = "02")]
+            #[diag(msg = "bad response")]
+            BadResponse {
+                /// Span of the response.
+                #[diag(span)]
+                span: SimpleSpan,
+            },
+        }
+    }
+}
+
+#[test]
+fn clone() {
+    let errors = [
+        RetryError::Timeout("example.com".into()),
+        RetryError::BadResponse {
+            span: SimpleSpan::new("response.json", "{}", 0, 2),
+        },
+    ];
+    for error in &errors {
+        let cloned = error.clone();
+        assert_eq!(&cloned, error);
+        assert_eq!(cloned.to_string(), error.to_string());
+        assert_eq!(cloned.primary_span(), error.primary_span());
+    }
+}
@@ -0,0 +1,162 @@
+//! Tests for codes built from `#[diag(code_base = "...", separator = "...")]`, with and without
+//! `#[diag(kind_prefix)]`, also when warnings are reported as errors.
+
+use error_enum::{error_type, ErrorType, ErrorTypeExt, Kind, RenderOptions};
+
+error_type! {
+    #[derive(Debug)]
+    #[diag(code_base = "PARSE", separator = ".")]
+    pub ParseError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "001")]
+            #[diag(msg = "unexpected token ({code})")]
+            UnexpectedToken,
+        },
+        #[diag(kind = "Warn")]
+        {
+            #[diag(number = "002")]
+            #[diag(msg = "trailing comma")]
+            TrailingComma,
+        },
+    }
+}
+
+error_type! {
+    #[derive(Debug)]
+    #[diag(code_base = "LOAD")]
+    pub LoadError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "1")]
+            #[diag(nested)]
+            Parse(ParseError),
+        },
+    }
+}
+
+error_type! {
+    #[derive(Debug)]
+    #[diag(code_base = "PARSE", separator = ".", kind_prefix)]
+    pub KindPrefixedError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "001")]
+            #[diag(msg = "unexpected token ({code})")]
+            UnexpectedToken,
+        },
+        #[diag(kind = "Warn")]
+        {
+            #[diag(number = "002")]
+            #[diag(msg = "trailing comma")]
+            TrailingComma,
+        },
+    }
+}
+
+error_type! {
+    #[derive(Debug)]
+    #[diag(code_base = "LOAD", kind_prefix)]
+    pub KindPrefixedLoadError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "1")]
+            #[diag(nested)]
+            Parse(ParseError),
+        },
+    }
+}
+
+error_type! {
+    #[derive(Debug)]
+    #[diag(code_base = "LINT", kind_prefix)]
+    pub KindPrefixedLintError {
+        {
+            #[diag(number = "2")]
+            #[diag(nested)]
+            Parse(ParseError),
+        },
+    }
+}
+
+error_type! {
+    #[derive(Debug)]
+    #[diag(code_base = "NEW")]
+    pub NewError {
+        #[diag(kind = "Warn")]
+        {
+            #[diag(number = "001")]
+            #[diag(msg = "new syntax")]
+            NewSyntax,
+        },
+    }
+}
+
+#[test]
+fn code_base() {
+    let error = ParseError::UnexpectedToken;
+    assert_eq!(error.code(), "PARSE.001");
+    assert_eq!(error.number(), "001");
+    assert_eq!(error.kind(), Kind::Error);
+    assert_eq!(error.to_string(), "unexpected token (PARSE.001)");
+    assert_eq!(ParseError::TrailingComma.code(), "PARSE.002");
+}
+
+#[test]
+fn code_base_nested() {
+    let error = LoadError::Parse(ParseError::UnexpectedToken);
+    assert_eq!(error.code(), "LOAD1001");
+    assert_eq!(LoadError::all_codes(), ["LOAD1001", "LOAD1002"]);
+}
+
+#[test]
+fn code_base_warnings_as_errors() {
+    let options = RenderOptions::new().with_warnings_as_errors(true);
+    let rendered = ParseError::TrailingComma.with_render_options(options);
+    assert_eq!(rendered.kind(), Kind::Error);
+    assert_eq!(rendered.code(), "PARSE.002");
+}
+
+#[test]
+fn code_base_ending_in_prefix_warnings_as_errors() {
+    let options = RenderOptions::new().with_warnings_as_errors(true);
+    let rendered = NewError::NewSyntax.with_render_options(options);
+    assert_eq!(rendered.kind(), Kind::Error);
+    assert_eq!(rendered.code(), "NEW001");
+}
+
+#[test]
+fn code_base_kind_prefix() {
+    let error = KindPrefixedError::UnexpectedToken;
+    assert_eq!(error.code(), "PARSE.E001");
+    assert_eq!(error.number(), "001");
+    assert_eq!(error.to_string(), "unexpected token (PARSE.E001)");
+    assert_eq!(KindPrefixedError::TrailingComma.code(), "PARSE.W002");
+    assert_eq!(KindPrefixedError::ALL_CODES, ["PARSE.E001", "PARSE.W002"]);
+    assert_eq!(ParseError::ALL_CODES, ["PARSE.001", "PARSE.002"]);
+}
+
+#[test]
+fn code_base_kind_prefix_nested() {
+    let error = KindPrefixedLoadError::Parse(ParseError::TrailingComma);
+    assert_eq!(error.code(), "LOADW1002");
+    assert_eq!(
+        KindPrefixedLoadError::all_codes(),
+        ["LOADE1001", "LOADW1002"]
+    );
+}
+
+#[test]
+fn code_base_kind_prefix_warnings_as_errors() {
+    let options = RenderOptions::new().with_warnings_as_errors(true);
+    let rendered = KindPrefixedError::TrailingComma.with_render_options(options);
+    assert_eq!(rendered.code(), "PARSE.E002");
+}
+
+#[test]
+fn code_base_kind_prefix_nested_warnings_as_errors() {
+    let options = RenderOptions::new().with_warnings_as_errors(true);
+    let error = KindPrefixedLintError::Parse(ParseError::TrailingComma);
+    let rendered = error.with_render_options(options);
+    assert_eq!(rendered.code(), "LINTE2002");
+}
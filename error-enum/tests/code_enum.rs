@@ -0,0 +1,106 @@
+//! Tests for the data-less code enum generated by `#[diag(code_enum)]`, and for `ALL_CODES` and
+//! `from_code`.
+
+use error_enum::{error_type, ErrorType, SimpleSpan};
+
+error_type! {
+    #[derive(Debug)]
+    #[diag(code_enum)]
+    pub FileSystemError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "01")]
+            #[diag(msg = "{path} not found")]
+            FileNotFound {
+                /// Path of the missing file.
+                path: String,
+                /// Span of the path.
+                #[diag(span)]
+                span: SimpleSpan,
+            },
+            #[diag(number = "02")]
+            #[diag(msg = "access denied")]
+            AccessDenied,
+        },
+        #[diag(kind = "Warn")]
+        {
+            #[diag(number = "01")]
+            #[diag(msg = "{0} is a symbolic link")]
+            SymbolicLink(String),
+        },
+    }
+}
+
+fn describe(error: &FileSystemError) -> &'static str {
+    match error.code_enum() {
+        FileSystemErrorCode::FileNotFound => "missing",
+        FileSystemErrorCode::AccessDenied => "denied",
+        FileSystemErrorCode::SymbolicLink => "link",
+    }
+}
+
+#[test]
+fn code_enum() {
+    let errors = [
+        FileSystemError::FileNotFound {
+            path: "a".into(),
+            span: SimpleSpan::default(),
+        },
+        FileSystemError::AccessDenied,
+        FileSystemError::SymbolicLink("b".into()),
+    ];
+    let described: Vec<_> = errors.iter().map(describe).collect();
+    assert_eq!(described, ["missing", "denied", "link"]);
+    assert_eq!(errors[1].code_enum(), FileSystemErrorCode::AccessDenied);
+    assert_eq!(errors[2].code(), "W01");
+}
+
+#[test]
+fn all_codes() {
+    assert_eq!(FileSystemError::ALL_CODES, ["E01", "E02", "W01"]);
+    let mut sorted = FileSystemError::ALL_CODES.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+    assert_eq!(sorted.len(), FileSystemError::ALL_CODES.len());
+}
+
+error_type! {
+    #[derive(Debug, PartialEq)]
+    pub LintError {
+        #[diag(kind = "Warn")]
+        {
+            #[diag(number = "01")]
+            #[diag(msg = "unused import")]
+            UnusedImport,
+            #[diag(number = "02")]
+            #[diag(msg = "dead code")]
+            DeadCode,
+        },
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "01")]
+            #[diag(msg = "denied lint")]
+            Denied,
+        },
+    }
+}
+
+#[test]
+fn from_code() {
+    for code in LintError::ALL_CODES {
+        let error = LintError::from_code(code);
+        assert_eq!(
+            error.map(|error| error.code().into_owned()).as_deref(),
+            Some(*code)
+        );
+    }
+    assert_eq!(LintError::from_code("W02"), Some(LintError::DeadCode));
+    assert_eq!(LintError::from_code("E02"), None);
+
+    // Variants with fields cannot be rebuilt from their code.
+    assert!(FileSystemError::from_code("E01").is_none());
+    assert!(matches!(
+        FileSystemError::from_code("E02"),
+        Some(FileSystemError::AccessDenied),
+    ));
+}
@@ -0,0 +1,49 @@
+//! Tests for the `{code}` placeholder in messages and labels.
+
+#![allow(clippy::unwrap_used)]
+
+use error_enum::{error_type, ErrorType, SimpleSpan};
+
+error_type! {
+    #[derive(Debug)]
+    pub ConfigError {
+        #[diag(kind = "Error")]
+        #[diag(number = "0")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "{code}: oops")]
+            #[diag(label = "see {code} for details")]
+            Oops(
+                #[diag(span)]
+                SimpleSpan,
+                #[diag(help("run `--explain {code}`"))]
+                SimpleSpan,
+            ),
+            #[diag(number = "1")]
+            #[diag(msg = "{{code}} is literal, {code} is not")]
+            Escaped,
+            #[diag(number = "2")]
+            #[diag(msg = "bad code `{code}`")]
+            Field {
+                /// A field shadowing the placeholder.
+                code: u32,
+            },
+        },
+    }
+}
+
+#[test]
+fn code_placeholder() {
+    let span = SimpleSpan::new("config.toml", "key = 1", 0, 3);
+    let error = ConfigError::Oops(span.clone(), span);
+    assert_eq!(error.to_string(), "E00: oops");
+    assert_eq!(error.primary_labels().first().1, "see E00 for details");
+    let (help, _, _) = error.additional().next().unwrap();
+    assert_eq!(help, "run `--explain E00`");
+
+    assert_eq!(
+        ConfigError::Escaped.to_string(),
+        "{code} is literal, E01 is not"
+    );
+    assert_eq!(ConfigError::Field { code: 7 }.to_string(), "bad code `7`");
+}
@@ -0,0 +1,133 @@
+//! Tests for asserting error codes of all variants.
+
+#![cfg(feature = "testing")]
+
+use error_enum::{
+    error_type,
+    testing::{assert_codes, assert_codes_with, assert_disjoint_codes},
+};
+
+error_type! {
+    #[derive(Debug)]
+    pub FileSystemError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "01")]
+            #[diag(msg = "{path} not found")]
+            FileNotFound {
+                /// Path of the missing file.
+                path: String,
+            },
+            #[diag(number = "02")]
+            #[diag(msg = "access denied")]
+            AccessDenied,
+        },
+        #[diag(kind = "Warn")]
+        {
+            #[diag(number = "01")]
+            #[diag(msg = "{path} is a symbolic link")]
+            SymbolicLink {
+                /// Path of the link.
+                path: String,
+            },
+        },
+    }
+}
+
+error_type! {
+    #[derive(Debug)]
+    pub DuplicatedError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "first")]
+            First,
+            #[diag(number = "0")]
+            #[diag(msg = "second")]
+            Second,
+        },
+    }
+}
+
+error_type! {
+    #[derive(Debug)]
+    pub ClashingError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "01")]
+            #[diag(msg = "clash")]
+            Clash,
+        },
+    }
+}
+
+error_type! {
+    #[derive(Debug)]
+    pub StorageError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "1")]
+            #[diag(nested)]
+            FileSystem(FileSystemError),
+            #[diag(number = "03")]
+            #[diag(msg = "disk full")]
+            DiskFull,
+        },
+    }
+}
+
+error_type! {
+    #[derive(Debug)]
+    pub LegacyError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "101")]
+            #[diag(msg = "legacy")]
+            Legacy,
+        },
+    }
+}
+
+#[test]
+fn codes() {
+    assert_codes(FileSystemError::ALL_CODES);
+    assert_codes_with(FileSystemError::ALL_CODES, |code| {
+        code.len() == 3 && code.starts_with(['E', 'W'])
+    });
+}
+
+#[test]
+#[should_panic = "duplicated error code \"E0\""]
+fn duplicated_codes() {
+    assert_codes(DuplicatedError::ALL_CODES);
+}
+
+#[test]
+#[should_panic = "malformed error code \"E01\""]
+fn malformed_codes() {
+    assert_codes_with(FileSystemError::ALL_CODES, |code| code.starts_with('W'));
+}
+
+#[test]
+#[should_panic = "malformed error code \"01\""]
+fn codes_without_prefix() {
+    assert_codes(&["01"]);
+}
+
+#[test]
+fn disjoint_codes() {
+    assert_disjoint_codes::<FileSystemError, DuplicatedError>();
+    assert_disjoint_codes::<StorageError, FileSystemError>();
+}
+
+#[test]
+#[should_panic = "error code \"E01\" is in both code sets"]
+fn clashing_codes() {
+    assert_disjoint_codes::<FileSystemError, ClashingError>();
+}
+
+#[test]
+#[should_panic = "error code \"E101\" is in both code sets"]
+fn clashing_nested_codes() {
+    assert_disjoint_codes::<StorageError, LegacyError>();
+}
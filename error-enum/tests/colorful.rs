@@ -63,6 +63,49 @@ fn basic() {
     assert_eq(&error.code(), "E01");
 }
 
+#[test]
+fn templates() {
+    let error = ColoredError::WhiteError {
+        white: "white".into(),
+        span: SimpleSpan::default(),
+    };
+    assert_eq!(error.message_template(), "All in {white}.");
+    assert_eq!(error.primary_label_template(), "check the color here");
+
+    let error = ColoredError::RedError(1, 2);
+    assert_eq!(error.primary_label_template(), "{0} and {1} is not red.");
+}
+
+#[test]
+fn primary_message_static() {
+    assert_eq!(
+        ColoredError::BlueError.primary_message_static(),
+        Some("I'm blue.")
+    );
+    assert_eq!(ColoredError::RedError(1, 2).primary_message_static(), None);
+    let error = ColoredError::WhiteError {
+        white: "white".into(),
+        span: SimpleSpan::default(),
+    };
+    assert_eq!(error.primary_message_static(), None);
+}
+
+#[test]
+fn sort_by_code() {
+    let mut errors = [
+        ColoredError::RedError(1, 2),
+        ColoredError::BlueError,
+        ColoredError::BlackError(0),
+    ];
+    error_enum::sort_by_code(&mut errors);
+    let codes: Vec<_> = errors
+        .iter()
+        .map(|error| error.code().into_owned())
+        .collect();
+    assert_eq!(codes, ["E00", "E01", "E03"]);
+    assert!(errors[0].code_cmp(&errors[1]).is_lt());
+}
+
 #[test]
 #[cfg(feature = "annotate-snippets")]
 fn annotate_snippets() {
@@ -151,6 +194,29 @@ fn ariadne() {
     }
 }
 
+#[test]
+#[cfg(feature = "ariadne")]
+fn ariadne_label_color() {
+    use ariadne::Config;
+    use error_enum::ErrorTypeExt;
+
+    let error = ColoredError::WhiteError {
+        white: "white".into(),
+        span: SimpleSpan::new("foo.rs", "use white;", 4, 9),
+    };
+    let s = error
+        .fmt_as_ariadne_report_with(Config::new().with_color(true))
+        .unwrap();
+    // Ariadne colors the labeled source and the label arrows character by character.
+    let source_line = s.lines().find(|line| line.contains("\x1b[31mw")).unwrap();
+    assert!(!source_line.contains("\x1b[31mu"), "{s:?}");
+    let label_line = s
+        .lines()
+        .find(|line| line.contains("check the color"))
+        .unwrap();
+    assert!(label_line.contains("\x1b[31m╰"), "{s:?}");
+}
+
 #[test]
 #[cfg(feature = "codespan-reporting")]
 fn codespan_reporting() {
@@ -254,4 +320,126 @@ E01
 ",
         );
     }
+
+    // The column counts characters, not bytes.
+    let error = ColoredError::WhiteError {
+        white: "white".into(),
+        span: SimpleSpan::new("foo.rs", "let 名前 = white;", 13, 18),
+    };
+    {
+        let s = error.fmt_as_miette_diagnostic_with(&GraphicalReportHandler::new_themed(
+            GraphicalTheme::unicode_nocolor(),
+        ));
+        assert_eq(
+            &s,
+            "\
+\u{1b}]8;;foo.rs\u{1b}\\E05 (link)\u{1b}]8;;\u{1b}\\
+
+  × All in white.
+   ╭─[foo.rs:1:10]
+ 1 │ let 名前 = white;
+   ·            ──┬──
+   ·              ╰── check the color here
+   ╰────
+",
+        );
+    }
+}
+
+#[test]
+fn compact() {
+    use error_enum::ErrorTypeExt;
+
+    let error = ColoredError::WhiteError {
+        white: "white".into(),
+        span: SimpleSpan::new("foo.rs", "fn main() {}\nuse white;", 17, 22),
+    };
+    assert_eq!(error.primary_location(), Some(("foo.rs".into(), 2, 5)));
+    assert_eq(
+        &error.fmt_compact(),
+        "error[E05]: All in white. (foo.rs:2:5)",
+    );
+
+    let error = ColoredError::RedError(1, 2);
+    assert_eq!(error.primary_location(), None);
+    assert_eq(&error.fmt_compact(), "error[E01]: 1 and 2 is not red.");
+}
+
+#[test]
+fn one_line() {
+    use error_enum::ErrorTypeExt;
+
+    let error = ColoredError::WhiteError {
+        white: "white".into(),
+        span: SimpleSpan::new("foo.rs", "fn main() {}\nuse white;", 17, 22),
+    };
+    assert_eq(&error.one_line(), "error [E05] All in white.");
+
+    let error = ColoredError::RedError(1, 2);
+    assert_eq(&error.one_line(), "error [E01] 1 and 2 is not red.");
+}
+
+#[test]
+fn gnu() {
+    use error_enum::ErrorTypeExt;
+
+    let error = ColoredError::WhiteError {
+        white: "white".into(),
+        span: SimpleSpan::new("foo.rs", "fn main() {}\nuse white;", 17, 22),
+    };
+    assert_eq(&error.fmt_as_gnu(), "foo.rs:2:5: error: All in white.");
+
+    let error = ColoredError::WhiteError {
+        white: "white".into(),
+        span: SimpleSpan::default(),
+    };
+    assert_eq(&error.fmt_as_gnu(), "error: All in white.");
+
+    let error = ColoredError::RedError(1, 2);
+    assert_eq(&error.fmt_as_gnu(), "error: 1 and 2 is not red.");
+}
+
+#[test]
+#[cfg(all(feature = "testing", feature = "std"))]
+fn assert_renders() {
+    use error_enum::{testing::assert_renders, OutputFormat};
+
+    let error = ColoredError::WhiteError {
+        white: "white".into(),
+        span: SimpleSpan::new("foo.rs", "fn main() {}\nuse white;", 17, 22),
+    };
+    assert_renders(
+        &error,
+        OutputFormat::Compact,
+        "error[E05]: All in white. (foo.rs:2:5)",
+    );
+    assert_renders(
+        &error,
+        OutputFormat::Gnu,
+        "foo.rs:2:5: error: All in white.",
+    );
+    #[cfg(feature = "codespan-reporting")]
+    assert_renders(
+        &error,
+        OutputFormat::Codespan,
+        "\
+error[E05]: All in white.
+  ┌─ foo.rs:2:5
+  │
+2 │ use white;
+  │     ^^^^^ check the color here
+
+",
+    );
+}
+
+#[test]
+#[cfg(all(feature = "testing", feature = "std"))]
+#[should_panic(expected = "rendered output mismatch")]
+fn assert_renders_mismatch() {
+    error_enum::testing::assert_renders(
+        &ColoredError::BlueError,
+        error_enum::OutputFormat::Compact,
+        "error[E03]: I'm red.",
+    );
 }
@@ -0,0 +1,50 @@
+//! Tests for several keys combined in a single `#[diag(...)]` attribute.
+
+use error_enum::{error_type, ErrorType, Kind};
+
+error_type! {
+    #[derive(Debug)]
+    pub CombinedError {
+        #[diag(kind = "Warn", number = "1")]
+        {
+            #[diag(number = "0", msg = "x")]
+            X,
+            #[diag(number = "1", msg = "y: {0}", label = "here")]
+            Y(u32),
+        },
+        #[diag(kind = "Error", number = "0", msg = "x")]
+        Z,
+    }
+}
+
+error_type! {
+    #[derive(Debug)]
+    pub SplitError {
+        #[diag(kind = "Warn")]
+        #[diag(number = "1")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "x")]
+            X,
+        },
+    }
+}
+
+#[test]
+fn combined_keys() {
+    assert_eq!(CombinedError::Z.kind(), Kind::Error);
+    assert_eq!(CombinedError::Z.code(), "E0");
+    assert_eq!(CombinedError::Z.to_string(), "x");
+
+    assert_eq!(CombinedError::X.kind(), Kind::Warn);
+    assert_eq!(CombinedError::X.code(), "W10");
+    assert_eq!(CombinedError::Y(3).code(), "W11");
+    assert_eq!(CombinedError::Y(3).to_string(), "y: 3");
+    assert_eq!(CombinedError::Y(3).primary_labels().first().1, "here");
+}
+
+#[test]
+fn combined_matches_split() {
+    assert_eq!(CombinedError::X.code(), SplitError::X.code());
+    assert_eq!(CombinedError::X.to_string(), SplitError::X.to_string());
+}
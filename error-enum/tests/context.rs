@@ -0,0 +1,50 @@
+//! Tests for messages rendered with a shared context.
+
+use error_enum::error_type;
+
+error_type! {
+    #[derive(Debug)]
+    #[diag(context = "str")]
+    pub ParseError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "unexpected token {0:?}")]
+            #[diag(context_msg = "{ctx}: unexpected token {0:?}")]
+            Unexpected(char),
+            #[diag(number = "1")]
+            #[diag(msg = "missing `{name}`")]
+            #[diag(context_msg = "{ctx}: missing `{name}`")]
+            Missing {
+                /// Name of the missing item.
+                name: String,
+            },
+            #[diag(number = "2")]
+            #[diag(msg = "unexpected end of file")]
+            UnexpectedEof,
+        },
+    }
+}
+
+#[test]
+fn context_message() {
+    let error = ParseError::Unexpected('}');
+    assert_eq!(error.to_string(), "unexpected token '}'");
+    assert_eq!(
+        error.primary_message_with("main.rs"),
+        "main.rs: unexpected token '}'"
+    );
+
+    let error = ParseError::Missing { name: "fn".into() };
+    assert_eq!(error.to_string(), "missing `fn`");
+    assert_eq!(error.primary_message_with("lib.rs"), "lib.rs: missing `fn`");
+}
+
+#[test]
+fn context_message_fallback() {
+    let error = ParseError::UnexpectedEof;
+    assert_eq!(
+        error.primary_message_with("main.rs"),
+        "unexpected end of file"
+    );
+}
@@ -0,0 +1,32 @@
+//! Tests for `#[diag(contiguous_numbers)]`.
+
+use error_enum::{error_type, ErrorType};
+
+error_type! {
+    #[derive(Debug)]
+    #[diag(contiguous_numbers)]
+    pub ContiguousError {
+        #[diag(kind = "Error", number = "0")]
+        {
+            #[diag(number = "0", msg = "first")]
+            First,
+            #[diag(number = "1", msg = "second")]
+            Second,
+        },
+        #[diag(kind = "Warn", number = "1")]
+        {
+            #[diag(number = "01", msg = "third")]
+            Third,
+            #[diag(number = "02", msg = "fourth")]
+            Fourth,
+        },
+    }
+}
+
+#[test]
+fn contiguous_numbers() {
+    assert_eq!(ContiguousError::First.code(), "E00");
+    assert_eq!(ContiguousError::Second.code(), "E01");
+    assert_eq!(ContiguousError::Third.code(), "W101");
+    assert_eq!(ContiguousError::Fourth.code(), "W102");
+}
@@ -0,0 +1,40 @@
+//! Tests for kind-based labels from `#[diag(default_labels)]`.
+
+use error_enum::{error_type, ErrorType, SimpleSpan};
+
+error_type! {
+    #[derive(Debug)]
+    #[diag(default_labels)]
+    pub LintError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "undefined variable")]
+            Undefined(#[diag(span)] SimpleSpan),
+            #[diag(number = "1")]
+            #[diag(msg = "type mismatch")]
+            #[diag(label = "expected `int`")]
+            Mismatch(#[diag(span)] SimpleSpan),
+        },
+        #[diag(kind = "Warn")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "unused variable")]
+            Unused(#[diag(span)] SimpleSpan),
+        },
+    }
+}
+
+#[test]
+fn default_labels() {
+    let span = SimpleSpan::new("main.rs", "let x = y;", 8, 9);
+    let error = LintError::Undefined(span.clone());
+    assert_eq!(error.primary_labels().first().1, "error occurred here");
+    assert_eq!(error.primary_label_template(), "error occurred here");
+
+    let error = LintError::Unused(span.clone());
+    assert_eq!(error.primary_labels().first().1, "warning occurred here");
+
+    let error = LintError::Mismatch(span);
+    assert_eq!(error.primary_labels().first().1, "expected `int`");
+}
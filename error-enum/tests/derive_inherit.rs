@@ -0,0 +1,50 @@
+//! Tests for enum-level attributes inherited by variants of the derive macro.
+
+use error_enum::{ErrorType, Kind};
+
+#[derive(Debug, ErrorType)]
+#[diag(kind = "Warn")]
+#[diag(number = "1")]
+enum LintWarning {
+    #[diag(number = "0")]
+    #[diag(msg = "unused variable")]
+    Unused,
+    #[diag(number = "1")]
+    #[diag(msg = "dead code")]
+    DeadCode,
+    #[diag(kind = "Error")]
+    #[diag(number = "2")]
+    #[diag(msg = "denied lint")]
+    Denied,
+}
+
+#[test]
+fn enum_level_kind_and_number() {
+    assert_eq!(LintWarning::Unused.kind(), Kind::Warn);
+    assert_eq!(LintWarning::Unused.code(), "W10");
+    assert_eq!(LintWarning::DeadCode.kind(), Kind::Warn);
+    assert_eq!(LintWarning::DeadCode.code(), "W11");
+    assert_eq!(LintWarning::Denied.kind(), Kind::Error);
+    assert_eq!(LintWarning::Denied.code(), "E12");
+}
+
+#[derive(Debug, ErrorType)]
+#[diag(kind = Warn)]
+enum StyleWarning {
+    #[diag(number = "0")]
+    #[diag(msg = "{code}: line too long")]
+    LineTooLong,
+    #[diag(number = "1")]
+    #[diag(msg = "trailing whitespace on line {line}")]
+    TrailingWhitespace { line: usize },
+}
+
+#[test]
+fn enum_level_kind_only() {
+    assert_eq!(StyleWarning::LineTooLong.kind(), Kind::Warn);
+    assert_eq!(StyleWarning::LineTooLong.to_string(), "W0: line too long");
+    let error = StyleWarning::TrailingWhitespace { line: 3 };
+    assert_eq!(error.kind(), Kind::Warn);
+    assert_eq!(error.severity_level(), 1);
+    assert_eq!(StyleWarning::ALL_CODES, ["W0", "W1"]);
+}
@@ -0,0 +1,48 @@
+//! Tests for the `ErrorType` impl derived on structs.
+
+use error_enum::{ErrorType, Kind, SimpleSpan};
+
+#[derive(Debug, ErrorType)]
+#[diag(kind = "Warn")]
+#[diag(number = "7")]
+#[diag(msg = "unused import `{name}`")]
+#[diag(label = "imported here")]
+struct UnusedImport {
+    name: String,
+    #[diag(span)]
+    span: SimpleSpan,
+}
+
+#[derive(Debug, ErrorType)]
+#[diag(msg = "failed to read string due to: {0}")]
+struct IoError(std::io::Error);
+
+#[test]
+fn struct_error_type() {
+    let span = SimpleSpan::new("lib.rs", "use std::fmt;", 4, 12);
+    let error = UnusedImport {
+        name: "std::fmt".into(),
+        span: span.clone(),
+    };
+    assert_eq!(error.kind(), Kind::Warn);
+    assert_eq!(error.number(), "7");
+    assert_eq!(error.code(), "W7");
+    assert_eq!(error.primary_span(), Some(span.clone()));
+    assert_eq!(error.primary_message(), "unused import `std::fmt`");
+    assert_eq!(
+        error.primary_labels().first(),
+        &(span, "imported here".into())
+    );
+}
+
+#[test]
+fn tuple_struct_error_type() {
+    let error = IoError(std::io::Error::other("disk error"));
+    assert_eq!(error.kind(), Kind::Error);
+    assert_eq!(error.code(), "E");
+    assert_eq!(error.primary_span(), None);
+    assert_eq!(
+        error.to_string(),
+        "failed to read string due to: disk error"
+    );
+}
@@ -0,0 +1,187 @@
+//! Tests for the [`Diagnostics`] container.
+
+#![allow(clippy::unwrap_used)]
+
+use error_enum::{error_type, Diagnostics, ErrorType, ErrorTypeExt, Kind, SimpleSpan};
+
+error_type! {
+    #[derive(Debug)]
+    pub LintError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "undefined variable")]
+            Undefined,
+        },
+        #[diag(kind = "Warn")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "unused variable")]
+            Unused,
+        },
+        #[diag(kind = "Note")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "variable shadowed")]
+            Shadowed,
+        },
+        #[diag(kind = "Help")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "consider renaming")]
+            Rename,
+        },
+    }
+}
+
+#[test]
+fn counts() {
+    let mut diagnostics: Diagnostics<LintError> = [
+        LintError::Unused,
+        LintError::Undefined,
+        LintError::Unused,
+        LintError::Unused,
+    ]
+    .into_iter()
+    .collect();
+    let counts = diagnostics.counts();
+    assert_eq!(counts.get(&Kind::Error), Some(&1));
+    assert_eq!(counts.get(&Kind::Warn), Some(&3));
+    assert_eq!(diagnostics.to_string(), "1 error, 3 warnings");
+
+    diagnostics.push(LintError::Undefined);
+    assert_eq!(diagnostics.len(), 5);
+    assert_eq!(diagnostics.to_string(), "2 errors, 3 warnings");
+}
+
+#[test]
+fn counts_notes() {
+    let mut diagnostics: Diagnostics<LintError> = [LintError::Shadowed, LintError::Rename]
+        .into_iter()
+        .collect();
+    assert_eq!(diagnostics.to_string(), "0 errors, 0 warnings, 2 notes");
+
+    diagnostics.push(LintError::Unused);
+    diagnostics.push(LintError::Rename);
+    assert_eq!(diagnostics.to_string(), "0 errors, 1 warning, 3 notes");
+}
+
+#[test]
+fn empty() {
+    let diagnostics = Diagnostics::<LintError>::new();
+    assert!(diagnostics.counts().is_empty());
+    assert_eq!(diagnostics.to_string(), "0 errors, 0 warnings");
+    assert_eq!(diagnostics.max_severity(), None);
+}
+
+#[test]
+fn max_severity() {
+    let mut diagnostics: Diagnostics<LintError> =
+        [LintError::Unused, LintError::Unused].into_iter().collect();
+    assert_eq!(diagnostics.max_severity(), Some(Kind::Warn));
+
+    diagnostics.push(LintError::Undefined);
+    diagnostics.push(LintError::Unused);
+    assert_eq!(diagnostics.max_severity(), Some(Kind::Error));
+}
+
+error_type! {
+    #[derive(Debug)]
+    pub SpannedError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "1")]
+            #[diag(msg = "undefined variable")]
+            Undefined(#[diag(span)] SimpleSpan),
+            #[diag(number = "0")]
+            #[diag(msg = "type mismatch")]
+            Mismatch(#[diag(span)] SimpleSpan),
+        },
+    }
+}
+
+#[test]
+fn sort() {
+    let a = "let x = y;\nlet z = w;";
+    let b = "fn main() {}";
+    let mut diagnostics: Diagnostics<SpannedError> = [
+        SpannedError::Undefined(SimpleSpan::default()),
+        SpannedError::Undefined(SimpleSpan::new("b.rs", b, 3, 7)),
+        SpannedError::Undefined(SimpleSpan::new("a.rs", a, 19, 20)),
+        SpannedError::Undefined(SimpleSpan::new("a.rs", a, 8, 9)),
+        SpannedError::Mismatch(SimpleSpan::new("a.rs", a, 8, 9)),
+        SpannedError::Mismatch(SimpleSpan::default()),
+    ]
+    .into_iter()
+    .collect();
+    diagnostics.sort();
+    let order: Vec<_> = diagnostics
+        .iter()
+        .map(|error| {
+            let (uri, line, col) = error
+                .primary_location()
+                .filter(|(uri, _, _)| !uri.is_empty())
+                .unwrap_or_default();
+            format!("{uri}:{line}:{col} {}", error.code())
+        })
+        .collect();
+    assert_eq!(
+        order,
+        [
+            "a.rs:1:9 E0",
+            "a.rs:1:9 E1",
+            "a.rs:2:9 E1",
+            "b.rs:1:4 E1",
+            ":0:0 E0",
+            ":0:0 E1",
+        ]
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn write_all() {
+    use error_enum::{OutputFormat, RenderOptions};
+
+    let source = "let x = y;";
+    let diagnostics: Diagnostics<SpannedError> = [
+        SpannedError::Undefined(SimpleSpan::new("a.rs", source, 8, 9)),
+        SpannedError::Mismatch(SimpleSpan::new("a.rs", source, 4, 5)),
+    ]
+    .into_iter()
+    .collect();
+
+    let mut buf = Vec::new();
+    diagnostics
+        .write_all(&mut buf, OutputFormat::Compact, RenderOptions::new())
+        .unwrap();
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        "error[E1]: undefined variable (a.rs:1:9)\n\nerror[E0]: type mismatch (a.rs:1:5)\n",
+    );
+}
+
+#[test]
+#[cfg(feature = "codespan-reporting")]
+fn write_all_codespan() {
+    use error_enum::{OutputFormat, RenderOptions};
+
+    let source = "let x = y;";
+    let diagnostics: Diagnostics<SpannedError> = [
+        SpannedError::Undefined(SimpleSpan::new("a.rs", source, 8, 9)),
+        SpannedError::Mismatch(SimpleSpan::new("a.rs", source, 4, 5)),
+    ]
+    .into_iter()
+    .collect();
+
+    let mut buf = Vec::new();
+    diagnostics
+        .write_all(&mut buf, OutputFormat::Codespan, RenderOptions::new())
+        .unwrap();
+    let output = String::from_utf8(buf).unwrap();
+    let blocks: Vec<_> = output.split("\n\n").collect();
+    assert_eq!(blocks.len(), 2, "{output}");
+    assert!(blocks[0].starts_with("error[E1]: undefined variable"));
+    assert!(blocks[1].starts_with("error[E0]: type mismatch"));
+    assert!(output.ends_with("type mismatch\n"), "{output}");
+}
@@ -0,0 +1,46 @@
+//! Tests for `{name:disp}` in messages, which renders paths through `Path::display`.
+
+use error_enum::error_type;
+use std::path::PathBuf;
+
+error_type! {
+    #[derive(Debug)]
+    #[diag(context = "str")]
+    pub PathError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "0", msg = "{path:?} not found")]
+            Debug {
+                /// Path of the missing file.
+                path: PathBuf,
+            },
+            #[diag(number = "1", msg = "{path:disp} not found")]
+            #[diag(context_msg = "{ctx}: {path:disp} ({path:?})")]
+            Display {
+                /// Path of the missing file.
+                path: PathBuf,
+            },
+            #[diag(number = "2", msg = "cannot copy {0:disp} to {to:disp}")]
+            #[diag(arg(to = 1))]
+            Copy(PathBuf, PathBuf),
+        },
+    }
+}
+
+#[test]
+fn display_path() {
+    let path = PathBuf::from("dir/file.txt");
+    let debug = PathError::Debug { path: path.clone() };
+    let display = PathError::Display { path: path.clone() };
+    assert_eq!(debug.to_string(), "\"dir/file.txt\" not found");
+    assert_eq!(display.to_string(), "dir/file.txt not found");
+    assert_eq!(display.to_string(), format!("{} not found", path.display()));
+    assert_eq!(
+        display.primary_message_with("read"),
+        "read: dir/file.txt (\"dir/file.txt\")",
+    );
+    assert_eq!(
+        PathError::Copy("a".into(), "b".into()).to_string(),
+        "cannot copy a to b",
+    );
+}
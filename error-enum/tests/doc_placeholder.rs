@@ -0,0 +1,47 @@
+//! Tests for `{doc}` in messages, which expands to the first line of the variant's doc comment.
+
+use error_enum::{error_type, ErrorType};
+
+error_type! {
+    #[derive(Debug)]
+    pub BuildError {
+        #[diag(kind = "Error")]
+        {
+            /// Compiling the crate
+            ///
+            /// Runs `rustc` on every target.
+            #[diag(number = "0", msg = "{doc} failed")]
+            #[diag(label = "{doc} stopped here")]
+            Compile(#[diag(span)] error_enum::SimpleSpan),
+            /// Linking `{name}`
+            #[diag(number = "1", msg = "{doc} failed with code {code}")]
+            Link {
+                /// Name of the binary.
+                name: String,
+                /// Exit code of the linker.
+                code: i32,
+            },
+            /// Running tests
+            #[diag(number = "2", msg = "{{doc}} is literal")]
+            Test,
+        },
+    }
+}
+
+#[test]
+fn doc_placeholder() {
+    let error = BuildError::Compile(Default::default());
+    assert_eq!(error.to_string(), "Compiling the crate failed");
+    assert_eq!(
+        error.primary_labels().first().1,
+        "Compiling the crate stopped here"
+    );
+
+    let error = BuildError::Link {
+        name: "app".into(),
+        code: 1,
+    };
+    assert_eq!(error.to_string(), "Linking `{name}` failed with code 1");
+
+    assert_eq!(BuildError::Test.to_string(), "{doc} is literal");
+}
@@ -0,0 +1,100 @@
+//! Tests for reporting errors of several error types through [`DynErrorType`].
+
+#![allow(clippy::unwrap_used)]
+
+use error_enum::{error_type, Box, DynErrorType, ErrorTypeExt, SimpleSpan, Span};
+
+error_type! {
+    #[derive(Debug)]
+    pub LexError {
+        #[diag(kind = "Error", number = "0")]
+        {
+            #[diag(number = "0", msg = "unexpected character {0:?}", label = "not allowed here")]
+            UnexpectedChar(char, #[diag(span)] SimpleSpan),
+        },
+    }
+}
+
+error_type! {
+    #[derive(Debug)]
+    pub ParseError {
+        #[diag(kind = "Error", number = "1")]
+        {
+            #[diag(number = "0", msg = "expected `{expected}`", label = "expected here")]
+            Expected {
+                /// The expected token.
+                expected: String,
+                /// Where the token is expected.
+                #[diag(span)]
+                span: SimpleSpan,
+            },
+        },
+        #[diag(kind = "Warn", number = "1")]
+        {
+            #[diag(number = "0", msg = "unnecessary semicolon", label = "remove it")]
+            ExtraSemicolon(#[diag(span)] SimpleSpan),
+        },
+    }
+}
+
+fn errors(source: &str) -> Vec<Box<DynErrorType<'static, SimpleSpan>>> {
+    vec![
+        LexError::UnexpectedChar('$', SimpleSpan::new("a.rs", source, 4, 5)).boxed(),
+        ParseError::Expected {
+            expected: ")".into(),
+            span: SimpleSpan::new("a.rs", source, 9, 10),
+        }
+        .boxed(),
+        ParseError::ExtraSemicolon(SimpleSpan::new("a.rs", source, 10, 11)).boxed(),
+    ]
+}
+
+#[test]
+fn boxed() {
+    let errors = errors("let $ = (1;;");
+    let codes: Vec<_> = errors
+        .iter()
+        .map(|error| error.code().into_owned())
+        .collect();
+    assert_eq!(codes, ["E00", "E10", "W10"]);
+    assert_eq!(errors[0].to_string(), "unexpected character '$'");
+    assert_eq!(errors[1].primary_span().unwrap().start(), 9);
+    assert_eq!(
+        errors[2].fmt_compact(),
+        "warning[W10]: unnecessary semicolon (a.rs:1:11)",
+    );
+}
+
+#[test]
+#[cfg(feature = "codespan-reporting")]
+fn codespan() {
+    use codespan_reporting::term::Config;
+    use error_enum::{Diagnostics, OutputFormat, RenderOptions};
+
+    let errors = errors("let $ = (1;;");
+    let rendered: Vec<_> = errors
+        .iter()
+        .map(|error| {
+            error
+                .fmt_as_codespan_diagnostic_with(Config::default(), None)
+                .unwrap()
+        })
+        .collect();
+    assert!(rendered[0].starts_with("error[E00]: unexpected character '$'"));
+    assert!(rendered[0].contains("not allowed here"), "{}", rendered[0]);
+    assert!(rendered[1].starts_with("error[E10]: expected `)`"));
+    assert!(rendered[2].starts_with("warning[W10]: unnecessary semicolon"));
+
+    let diagnostics: Diagnostics<_> = errors.into_iter().collect();
+    let mut buf = Vec::new();
+    diagnostics
+        .as_deref()
+        .write_all(&mut buf, OutputFormat::Codespan, RenderOptions::new())
+        .unwrap();
+    let output = String::from_utf8(buf).unwrap();
+    let blocks: Vec<_> = output.split("\n\n").collect();
+    assert_eq!(blocks.len(), 3, "{output}");
+    for (block, rendered) in blocks.iter().zip(&rendered) {
+        assert_eq!(block.trim_end(), rendered.trim_end());
+    }
+}
@@ -0,0 +1,29 @@
+//! Tests for error types without variants.
+
+use error_enum::{error_type, ErrorType};
+
+error_type! {
+    #[derive(Debug)]
+    pub EmptyError {}
+}
+
+#[derive(Debug, ErrorType)]
+enum EmptyDerived {}
+
+#[allow(dead_code)]
+fn code_of(error: &EmptyError) -> String {
+    error.code().into_owned()
+}
+
+#[allow(dead_code)]
+fn message_of(error: &EmptyDerived) -> String {
+    error.to_string()
+}
+
+#[test]
+fn empty_errors() {
+    let errors: Vec<EmptyError> = Vec::new();
+    assert!(errors.iter().map(code_of).next().is_none());
+    let errors: Vec<EmptyDerived> = Vec::new();
+    assert!(errors.iter().map(message_of).next().is_none());
+}
@@ -0,0 +1,50 @@
+//! Tests for `Error::source` from `#[diag(source)]` fields.
+
+#![allow(clippy::unwrap_used)]
+#![expect(clippy::std_instead_of_core)]
+
+use core::error::Error;
+use error_enum::ErrorType;
+use std::{io, num::ParseIntError};
+
+#[derive(Debug, ErrorType)]
+enum ReadIntError {
+    #[diag(msg = "failed to parse integer")]
+    ParseInt(#[diag(source)] ParseIntError),
+    #[diag(msg = "failed to read {path}")]
+    Read {
+        path: String,
+        #[diag(source)]
+        error: io::Error,
+    },
+    #[diag(msg = "no input")]
+    Empty,
+}
+
+#[derive(Debug, ErrorType)]
+#[diag(msg = "failed to load")]
+struct LoadError(u32, #[diag(source)] io::Error);
+
+#[test]
+fn tuple_source() {
+    let error = ReadIntError::ParseInt("abc".parse::<i32>().unwrap_err());
+    let source = error.source().unwrap();
+    assert!(source.is::<ParseIntError>());
+    assert_eq!(source.to_string(), "invalid digit found in string");
+}
+
+#[test]
+fn named_source() {
+    let error = ReadIntError::Read {
+        path: "a.txt".into(),
+        error: io::Error::other("disk error"),
+    };
+    assert_eq!(error.source().unwrap().to_string(), "disk error");
+    assert!(ReadIntError::Empty.source().is_none());
+}
+
+#[test]
+fn struct_source() {
+    let error = LoadError(1, io::Error::other("gone"));
+    assert_eq!(error.source().unwrap().to_string(), "gone");
+}
@@ -0,0 +1,39 @@
+//! Tests for the `Info`, `Note` and `Help` built-in kinds.
+
+use error_enum::{error_type, ErrorType, ErrorTypeExt, Kind};
+
+error_type! {
+    #[derive(Debug)]
+    pub Remark {
+        #[diag(kind = "info")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "compiling 3 crates")]
+            Progress,
+        },
+        #[diag(kind = Note)]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "first defined here")]
+            Defined,
+        },
+        #[diag(kind = "Help")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "consider borrowing")]
+            Borrow,
+        },
+    }
+}
+
+#[test]
+fn extra_kinds() {
+    assert_eq!(Remark::Progress.kind(), Kind::Info);
+    assert_eq!(Remark::Progress.code(), "I0");
+    assert_eq!(Remark::Defined.kind(), Kind::Note);
+    assert_eq!(Remark::Defined.code(), "N0");
+    assert_eq!(Remark::Borrow.kind(), Kind::Help);
+    assert_eq!(Remark::Borrow.code(), "H0");
+    assert_eq!(Remark::Borrow.fmt_compact(), "help[H0]: consider borrowing");
+    assert_eq!(Remark::Defined.severity_level(), 2);
+}
@@ -0,0 +1,51 @@
+//! Tests for messages inherited from the root.
+
+use error_enum::{error_type, ErrorType};
+
+error_type! {
+    #[derive(Debug)]
+    #[diag(msg = "internal error")]
+    pub InternalError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "0")]
+            Unknown,
+            #[diag(number = "1")]
+            #[diag(msg = "unsupported {0}")]
+            #[diag(label = "not supported")]
+            Unsupported(u8),
+        },
+    }
+}
+
+#[derive(Debug, ErrorType)]
+#[diag(msg = "internal error")]
+#[diag(label = "raised here")]
+enum DerivedError {
+    #[diag(number = "0")]
+    Unknown,
+    #[diag(number = "1")]
+    #[diag(msg = "unsupported {0}")]
+    Unsupported(u8),
+}
+
+#[test]
+fn root_fallback() {
+    assert_eq!(InternalError::Unknown.to_string(), "internal error");
+    assert_eq!(
+        InternalError::Unknown.primary_labels().first().1,
+        "internal error"
+    );
+    assert_eq!(InternalError::Unsupported(7).to_string(), "unsupported 7");
+    assert_eq!(
+        InternalError::Unsupported(7).primary_labels().first().1,
+        "not supported"
+    );
+
+    assert_eq!(DerivedError::Unknown.to_string(), "internal error");
+    assert_eq!(
+        DerivedError::Unknown.primary_labels().first().1,
+        "raised here"
+    );
+    assert_eq!(DerivedError::Unsupported(7).to_string(), "unsupported 7");
+}
@@ -0,0 +1,58 @@
+//! Tests for `From` impls generated by `#[diag(from)]`.
+
+#![allow(clippy::unwrap_used)]
+
+use error_enum::{error_type, ErrorType};
+
+error_type! {
+    #[derive(Debug)]
+    ParseError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "01")]
+            #[diag(msg = "unexpected end of input")]
+            Eof,
+        }
+    }
+}
+
+error_type! {
+    #[derive(Debug)]
+    LoadError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "01")]
+            #[diag(nested, transparent, from)]
+            Parse(ParseError),
+            #[diag(number = "02")]
+            #[diag(msg = "invalid number `{value}`", from)]
+            Number {
+                /// The offending value.
+                value: i64,
+            },
+        }
+    }
+}
+
+fn parse() -> Result<(), ParseError> {
+    Err(ParseError::Eof)
+}
+
+fn load() -> Result<(), LoadError> {
+    parse()?;
+    Ok(())
+}
+
+#[test]
+fn from_nested() {
+    let error = load().unwrap_err();
+    assert!(matches!(error, LoadError::Parse(ParseError::Eof)));
+    assert_eq!(error.to_string(), "unexpected end of input");
+}
+
+#[test]
+fn from_named() {
+    let error = LoadError::from(-3);
+    assert!(matches!(error, LoadError::Number { value: -3 }));
+    assert_eq!(error.code(), "E02");
+}
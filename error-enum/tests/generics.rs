@@ -0,0 +1,39 @@
+//! Tests for generic error types with `where` clauses.
+
+#![allow(clippy::unwrap_used)]
+
+use core::fmt::Debug;
+use error_enum::{error_type, ErrorType, SimpleSpan, Span};
+
+error_type! {
+    #[derive(Debug)]
+    #[diag(span_type = "S")]
+    pub SpannedError<S>
+    where
+        S: Span + Debug + Default + 'static + for<'a> From<&'a S>,
+    {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "unexpected token")]
+            #[diag(label = "here")]
+            Unexpected(#[diag(span)] S),
+            #[diag(number = "1")]
+            #[diag(msg = "unexpected end of file")]
+            UnexpectedEof,
+        },
+    }
+}
+
+#[test]
+fn where_clause() {
+    let span = SimpleSpan::new("main.rs", "let x = $;", 8, 9);
+    let error = SpannedError::Unexpected(span.clone());
+    assert_eq!(error.code().as_ref(), "E0");
+    assert_eq!(error.primary_span().unwrap(), span);
+    assert_eq!(error.primary_labels().first().0, span);
+
+    let error = SpannedError::<SimpleSpan>::UnexpectedEof;
+    assert_eq!(error.to_string(), "unexpected end of file");
+    assert!(error.primary_span().is_none());
+}
@@ -0,0 +1,72 @@
+//! Tests for [`ErrorTypeExt::help`] and [`ErrorTypeExt::notes`].
+
+use error_enum::{error_type, ErrorTypeExt};
+
+error_type! {
+    #[derive(Debug)]
+    pub ConfigError {
+        #[diag(kind = "Error")]
+        #[diag(help("see the configuration reference"))]
+        {
+            #[diag(number = "0", msg = "unknown key `{key}`")]
+            #[diag(help("did you mean `{suggestion}`?"))]
+            UnknownKey {
+                /// The unknown key.
+                key: String,
+                /// The closest known key.
+                suggestion: String,
+            },
+            #[diag(number = "1", msg = "missing value")]
+            MissingValue,
+        },
+        #[diag(kind = "Warn")]
+        {
+            #[diag(number = "0", msg = "deprecated key")]
+            #[diag(note("it will be removed"))]
+            #[diag(note("it was deprecated in v{since}"))]
+            Deprecated {
+                /// Major version deprecating the key.
+                since: u32,
+            },
+        },
+    }
+}
+
+#[test]
+fn help() {
+    let error = ConfigError::UnknownKey {
+        key: "colour".into(),
+        suggestion: "color".into(),
+    };
+    assert_eq!(
+        error.help().as_deref(),
+        Some("see the configuration reference\ndid you mean `color`?"),
+    );
+    assert_eq!(
+        ConfigError::MissingValue.help().as_deref(),
+        Some("see the configuration reference"),
+    );
+    assert_eq!(ConfigError::Deprecated { since: 2 }.help(), None);
+}
+
+#[test]
+fn notes() {
+    assert_eq!(
+        ConfigError::Deprecated { since: 2 }.notes(),
+        ["it will be removed", "it was deprecated in v2"],
+    );
+    assert!(ConfigError::MissingValue.notes().is_empty());
+}
+
+#[test]
+#[cfg(feature = "miette")]
+fn miette_help() {
+    use miette::Diagnostic as _;
+
+    let error = ConfigError::MissingValue;
+    let help = error
+        .as_miette_diagnostic()
+        .help()
+        .map(|help| help.to_string());
+    assert_eq!(help.as_deref(), Some("see the configuration reference"));
+}
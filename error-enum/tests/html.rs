@@ -0,0 +1,52 @@
+//! Tests for HTML output.
+
+#![cfg(feature = "html")]
+#![allow(clippy::unwrap_used)]
+
+use error_enum::{error_type, ErrorTypeExt, SimpleSpan};
+
+error_type! {
+    #[derive(Debug)]
+    pub TemplateError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "unknown tag `<{tag}>`")]
+            #[diag(label = "not a known tag")]
+            UnknownTag {
+                /// Tag name.
+                tag: String,
+                /// Tag.
+                #[diag(span)]
+                span: SimpleSpan,
+                /// Suggestion.
+                #[diag(help("escape `<` as `&lt;`"))]
+                help: SimpleSpan,
+            },
+        },
+    }
+}
+
+#[test]
+fn html() {
+    let source = "<p>a & b</p>\n<x>\n";
+    let span = SimpleSpan::new("index.html", source, 13, 16);
+    let error = TemplateError::UnknownTag {
+        tag: "x".into(),
+        span: span.clone(),
+        help: span.with_range(0, 1),
+    };
+    assert_eq!(
+        error.fmt_as_html().unwrap(),
+        "\
+<pre class=\"diagnostic error\"><span class=\"error-header\"><span class=\"error-kind\">error</span>[<span class=\"error-code\">E0</span>]: <span class=\"error-message\">unknown tag `&lt;x&gt;`</span></span>
+<span class=\"error-location\">index.html:2:1</span>
+<span class=\"error-source\"><mark class=\"error-span\">&lt;x&gt;</mark></span>
+<span class=\"error-label\">not a known tag</span>
+<span class=\"error-help\">help: escape `&lt;` as `&amp;lt;`</span>
+<span class=\"error-location\">index.html:1:1</span>
+<span class=\"error-source\"><mark class=\"error-span\">&lt;</mark>p&gt;a &amp; b&lt;/p&gt;</span>
+<span class=\"error-label\">escape `&lt;` as `&amp;lt;`</span>
+</pre>",
+    );
+}
@@ -0,0 +1,85 @@
+//! Tests for OSC 8 hyperlinks to error documentation.
+
+use error_enum::{error_type, ErrorTypeExt, SimpleSpan};
+
+error_type! {
+    #[derive(Debug)]
+    pub FileSystemError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "01")]
+            #[diag(msg = "{path} not found")]
+            FileNotFound {
+                /// Path of the missing file.
+                path: String,
+                /// Span of the path.
+                #[diag(span)]
+                span: SimpleSpan,
+            },
+        },
+    }
+}
+
+fn file_not_found() -> FileSystemError {
+    FileSystemError::FileNotFound {
+        path: "a.txt".into(),
+        span: SimpleSpan::new("main.rs", "open(a.txt)", 5, 10),
+    }
+}
+
+const LINKED: &str = "error[\x1b]8;;https://example.com/errors/E01\x1b\\E01\x1b]8;;\x1b\\]: a.txt not found (main.rs:1:6)";
+
+#[test]
+#[cfg(not(feature = "std"))]
+fn compact_with_link() {
+    assert_eq!(
+        file_not_found().fmt_compact_with_link("https://example.com/errors/{code}"),
+        LINKED,
+    );
+}
+
+/// Restores `NO_COLOR` and `TERM` when dropped.
+#[cfg(feature = "std")]
+struct EnvGuard(Option<std::ffi::OsString>, Option<std::ffi::OsString>);
+
+#[cfg(feature = "std")]
+impl EnvGuard {
+    fn new() -> Self {
+        Self(std::env::var_os("NO_COLOR"), std::env::var_os("TERM"))
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for EnvGuard {
+    fn drop(&mut self) {
+        for (name, value) in [("NO_COLOR", &self.0), ("TERM", &self.1)] {
+            match value {
+                Some(value) => std::env::set_var(name, value),
+                None => std::env::remove_var(name),
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn hyperlinks_enabled() {
+    // Only this test touches the environment in this binary.
+    let _guard = EnvGuard::new();
+    let error = file_not_found();
+    let url = "https://example.com/errors/{code}";
+
+    std::env::remove_var("NO_COLOR");
+    std::env::set_var("TERM", "xterm-256color");
+    assert!(error_enum::hyperlinks_enabled());
+    assert_eq!(error.fmt_compact_with_link(url), LINKED);
+    std::env::set_var("TERM", "dumb");
+    assert!(!error_enum::hyperlinks_enabled());
+    assert_eq!(error.fmt_compact_with_link(url), error.fmt_compact());
+    std::env::set_var("TERM", "xterm-256color");
+    std::env::set_var("NO_COLOR", "1");
+    assert!(!error_enum::hyperlinks_enabled());
+    assert_eq!(error.fmt_compact_with_link(url), error.fmt_compact());
+    std::env::set_var("NO_COLOR", "");
+    assert!(error_enum::hyperlinks_enabled());
+}
@@ -0,0 +1,65 @@
+//! Tests for primary spans inferred from the field type.
+
+use error_enum::{error_type, ErrorType, SimpleSpan, Span};
+
+error_type! {
+    #[derive(Debug)]
+    #[diag(infer_span)]
+    pub ParseError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "unexpected `{token}`")]
+            Unexpected {
+                /// The unexpected token.
+                token: String,
+                /// Where the token is.
+                at: SimpleSpan,
+            },
+            #[diag(number = "1")]
+            #[diag(msg = "unexpected end of file")]
+            Eof(error_enum::SimpleSpan),
+            #[diag(number = "2")]
+            #[diag(msg = "mismatched delimiters")]
+            Mismatched {
+                /// Opening delimiter.
+                open: SimpleSpan,
+                /// Closing delimiter.
+                #[diag(span)]
+                close: SimpleSpan,
+            },
+            #[diag(number = "3")]
+            #[diag(msg = "ambiguous")]
+            Ambiguous(SimpleSpan, SimpleSpan),
+        },
+    }
+}
+
+#[test]
+fn inferred_span() {
+    let source = "let x = (1];";
+    let error = ParseError::Unexpected {
+        token: "]".into(),
+        at: SimpleSpan::new("main.rs", source, 10, 11),
+    };
+    assert_eq!(error.primary_span().map(|span| span.range()), Some(10..11));
+
+    let error = ParseError::Eof(SimpleSpan::new("main.rs", source, 12, 12));
+    assert_eq!(error.primary_span().map(|span| span.range()), Some(12..12));
+}
+
+#[test]
+fn explicit_or_ambiguous_span() {
+    let source = "let x = (1];";
+    let error = ParseError::Mismatched {
+        open: SimpleSpan::new("main.rs", source, 8, 9),
+        close: SimpleSpan::new("main.rs", source, 10, 11),
+    };
+    assert_eq!(error.primary_span().map(|span| span.range()), Some(10..11));
+
+    let error = ParseError::Ambiguous(
+        SimpleSpan::new("main.rs", source, 8, 9),
+        SimpleSpan::new("main.rs", source, 10, 11),
+    );
+    assert_eq!(error.primary_span(), None);
+}
@@ -0,0 +1,42 @@
+//! Tests for `#[diag(kind = ...)]` written as a bare identifier.
+
+use error_enum::{error_type, ErrorType, Kind};
+
+error_type! {
+    #[derive(Debug)]
+    pub LintError {
+        #[diag(kind = Warn)]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "unused variable")]
+            Unused,
+        },
+        #[diag(kind = Error)]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "denied lint")]
+            Denied,
+        },
+        #[diag(kind = "Warn")]
+        {
+            #[diag(number = "1")]
+            #[diag(msg = "dead code")]
+            DeadCode,
+        },
+    }
+}
+
+#[derive(Debug, ErrorType)]
+#[diag(kind = Warn, number = "2", msg = "shadowed binding")]
+struct Shadowed;
+
+#[test]
+fn bare_ident_kind() {
+    assert_eq!(LintError::Unused.kind(), Kind::Warn);
+    assert_eq!(LintError::Unused.code(), "W0");
+    assert_eq!(LintError::Denied.kind(), Kind::Error);
+    assert_eq!(LintError::Denied.code(), "E0");
+    assert_eq!(LintError::DeadCode.code(), "W1");
+    assert_eq!(Shadowed.kind(), Kind::Warn);
+    assert_eq!(Shadowed.code(), "W2");
+}
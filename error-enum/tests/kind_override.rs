@@ -0,0 +1,30 @@
+//! Tests for overriding the inherited kind on a leaf.
+
+use error_enum::{error_type, ErrorType, Kind};
+
+error_type! {
+    #[derive(Debug)]
+    pub ImportError {
+        #[diag(kind = "Error")]
+        #[diag(number = "1")]
+        {
+            #[diag(number = "1")]
+            #[diag(msg = "unresolved import")]
+            Unresolved,
+            #[diag(kind = "Warn")]
+            #[diag(number = "2")]
+            #[diag(msg = "unused import")]
+            Unused,
+        },
+    }
+}
+
+#[test]
+fn leaf_kind_keeps_number() {
+    assert_eq!(ImportError::Unresolved.kind(), Kind::Error);
+    assert_eq!(ImportError::Unresolved.code(), "E11");
+
+    assert_eq!(ImportError::Unused.kind(), Kind::Warn);
+    assert_eq!(ImportError::Unused.number(), "12");
+    assert_eq!(ImportError::Unused.code(), "W12");
+}
@@ -0,0 +1,62 @@
+//! Tests for reporting errors through the `log` facade.
+
+#![cfg(feature = "log")]
+#![allow(clippy::unwrap_used)]
+
+use error_enum::{error_type, ErrorTypeExt, Kind};
+use log::{Level, Log, Metadata, Record};
+use std::sync::Mutex;
+
+error_type! {
+    #[derive(Debug)]
+    pub LintError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "0", msg = "undefined variable")]
+            Undefined,
+        },
+        #[diag(kind = "Warn")]
+        {
+            #[diag(number = "0", msg = "unused variable")]
+            Unused,
+            #[diag(number = "1", msg = "shadowed variable", level = 2)]
+            Shadowed,
+        },
+    }
+}
+
+/// Logger keeping every record for inspection.
+struct Capture(Mutex<Vec<(Level, String)>>);
+
+impl Log for Capture {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+    fn log(&self, record: &Record<'_>) {
+        let entry = (record.level(), record.args().to_string());
+        self.0.lock().unwrap().push(entry);
+    }
+    fn flush(&self) {}
+}
+
+static LOGGER: Capture = Capture(Mutex::new(Vec::new()));
+
+#[test]
+fn log_records() {
+    assert_eq!(Level::from(Kind::Error), Level::Error);
+    assert_eq!(Level::from(Kind::Warn), Level::Warn);
+
+    log::set_logger(&LOGGER).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+    LintError::Undefined.log();
+    LintError::Unused.log();
+    LintError::Shadowed.log();
+    assert_eq!(
+        *LOGGER.0.lock().unwrap(),
+        [
+            (Level::Error, "error[E0]: undefined variable".to_owned()),
+            (Level::Warn, "warning[W0]: unused variable".to_owned()),
+            (Level::Info, "warning[W1]: shadowed variable".to_owned()),
+        ],
+    );
+}
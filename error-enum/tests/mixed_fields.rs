@@ -0,0 +1,63 @@
+//! Tests for one derived enum mixing unit, named and tuple variants.
+
+#![allow(clippy::unwrap_used)]
+
+use error_enum::{ErrorType, SimpleSpan};
+
+#[derive(Debug, ErrorType)]
+#[diag(kind = "Error")]
+enum ConfigError {
+    #[diag(number = "0")]
+    #[diag(msg = "configuration is empty")]
+    #[diag(help("add at least one section"))]
+    Empty,
+    #[diag(number = "1")]
+    #[diag(msg = "unknown key `{key}`")]
+    #[diag(label = "not a known key")]
+    UnknownKey {
+        key: String,
+        #[diag(span)]
+        span: SimpleSpan,
+    },
+    #[diag(kind = "Warn")]
+    #[diag(number = "2")]
+    #[diag(msg = "value {1} of `{0}` is deprecated")]
+    #[diag(label = "deprecated")]
+    Deprecated(String, u32, #[diag(span)] SimpleSpan),
+}
+
+#[test]
+fn mixed_variants() {
+    let source = "[server]\nport = 80\nmode = 1";
+    let empty = ConfigError::Empty;
+    assert_eq!(empty.to_string(), "configuration is empty");
+    assert_eq!(empty.code(), "E0");
+    assert_eq!(empty.primary_span(), None);
+    assert_eq!(
+        empty.additional().next().unwrap().0,
+        "add at least one section"
+    );
+
+    let span = SimpleSpan::new("a.toml", source, 9, 13);
+    let unknown = ConfigError::UnknownKey {
+        key: "port".into(),
+        span: span.clone(),
+    };
+    assert_eq!(unknown.to_string(), "unknown key `port`");
+    assert_eq!(unknown.code(), "E1");
+    assert_eq!(unknown.primary_span(), Some(span.clone()));
+    assert_eq!(
+        unknown.primary_labels().first(),
+        &(span, "not a known key".into())
+    );
+
+    let span = SimpleSpan::new("a.toml", source, 19, 23);
+    let deprecated = ConfigError::Deprecated("mode".into(), 1, span.clone());
+    assert_eq!(deprecated.to_string(), "value 1 of `mode` is deprecated");
+    assert_eq!(deprecated.code(), "W2");
+    assert_eq!(deprecated.primary_span(), Some(span.clone()));
+    assert_eq!(
+        deprecated.primary_labels().first(),
+        &(span, "deprecated".into())
+    );
+}
@@ -0,0 +1,89 @@
+//! Tests for spans over memory-mapped files.
+
+#![cfg(feature = "memmap2")]
+#![allow(clippy::unwrap_used)]
+
+use error_enum::{error_type, ErrorTypeExt, MmapSource, MmapSpan, Span};
+use memmap2::Mmap;
+use std::fs::{self, File};
+
+error_type! {
+    #[derive(Debug)]
+    #[diag(span_type = "MmapSpan")]
+    pub ScanError {
+        #[diag(kind = "Warn")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "trailing whitespace")]
+            #[diag(label = "remove this")]
+            TrailingWhitespace(#[diag(span)] MmapSpan),
+        },
+    }
+}
+
+fn map(name: &str, contents: &str) -> MmapSource {
+    let path = std::env::temp_dir().join(format!("{}-{name}", std::process::id()));
+    fs::write(&path, contents).unwrap();
+    let file = File::open(&path).unwrap();
+    // SAFETY: the file is private to this test and never modified after mapping.
+    let mmap = unsafe { Mmap::map(&file) }.unwrap();
+    fs::remove_file(&path).unwrap();
+    MmapSource::new(mmap).unwrap()
+}
+
+#[test]
+fn mmap_span() {
+    let source = map("scan.txt", "first line\nsecond line  \nthird line\n");
+    let span = MmapSpan::new("scan.txt", source.clone(), 22, 24);
+    assert_eq!(span.source_text().as_ref().len(), 36);
+    assert_eq!(span.start_line_col(), (2, 12));
+    assert!(span.share_source_text(&span.with_range(0, 5)));
+
+    // Spans over one source share its line indexer instead of rescanning the file.
+    let other = MmapSpan::new("scan.txt", source.clone(), 0, 5);
+    assert!(core::ptr::eq(
+        &**span.source_index(),
+        &**other.source_index()
+    ));
+
+    let error = ScanError::TrailingWhitespace(span);
+    assert_eq!(error.primary_span_text().as_deref(), Some("  "));
+    assert_eq!(
+        error.fmt_compact(),
+        "warning[W0]: trailing whitespace (scan.txt:2:12)"
+    );
+}
+
+#[test]
+fn invalid_utf8() {
+    let path = std::env::temp_dir().join(format!("{}-invalid.bin", std::process::id()));
+    fs::write(&path, [0x66, 0x6f, 0xff]).unwrap();
+    let file = File::open(&path).unwrap();
+    // SAFETY: the file is private to this test and never modified after mapping.
+    let mmap = unsafe { Mmap::map(&file) }.unwrap();
+    fs::remove_file(&path).unwrap();
+    assert!(MmapSource::new(mmap).is_err());
+}
+
+#[test]
+#[cfg(feature = "codespan-reporting")]
+fn codespan_reporting() {
+    use codespan_reporting::term::Config;
+
+    let source = map("render.txt", "a  \nb\n");
+    let error = ScanError::TrailingWhitespace(MmapSpan::new("render.txt", source, 1, 3));
+    let s = error
+        .fmt_as_codespan_diagnostic_with(Config::default(), None)
+        .unwrap();
+    assert_eq!(
+        s,
+        "\
+warning[W0]: trailing whitespace
+  ┌─ render.txt:1:2
+  │
+1 │ a  
+  │  ^^ remove this
+
+",
+    );
+}
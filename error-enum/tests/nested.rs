@@ -0,0 +1,176 @@
+//! Tests for `#[diag(nested)]` wrappers.
+
+#![allow(clippy::unwrap_used)]
+
+use error_enum::{
+    error_type, vec1, AdditionalKind, Box, Cow, ErrorType, Kind, LabelVec1, SimpleSpan, String,
+};
+
+error_type! {
+    #[derive(Debug)]
+    Inner {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "23")]
+            #[diag(msg = "inner failure")]
+            Fail,
+            #[diag(number = "24")]
+            #[diag(msg = "bad value `{0}`")]
+            Value(i32),
+        }
+    }
+}
+
+error_type! {
+    #[derive(Debug)]
+    Outer {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "01")]
+            #[diag(nested, transparent)]
+            Transparent(Inner),
+            #[diag(number = "02")]
+            #[diag(nested, transparent)]
+            Named {
+                /// The wrapped error.
+                inner: Inner,
+            },
+            #[diag(number = "03")]
+            #[diag(msg = "{0}")]
+            Manual(Inner),
+        }
+    }
+}
+
+#[test]
+fn transparent_display() {
+    let cases = [
+        (Outer::Transparent(Inner::Fail), Outer::Manual(Inner::Fail)),
+        (
+            Outer::Transparent(Inner::Value(7)),
+            Outer::Manual(Inner::Value(7)),
+        ),
+    ];
+    for (transparent, manual) in cases {
+        assert_eq!(transparent.to_string(), manual.to_string());
+        assert_eq!(transparent.primary_message(), manual.primary_message());
+    }
+
+    let named = Outer::Named {
+        inner: Inner::Value(7),
+    };
+    assert_eq!(named.to_string(), "bad value `7`");
+    assert_eq!(named.code().as_ref(), "E0224");
+}
+
+#[test]
+fn all_codes() {
+    assert_eq!(
+        Outer::all_codes(),
+        ["E0123", "E0124", "E0223", "E0224", "E03"]
+    );
+    for code in Outer::all_codes() {
+        let error = [
+            Outer::Transparent(Inner::Fail),
+            Outer::Transparent(Inner::Value(0)),
+            Outer::Named { inner: Inner::Fail },
+            Outer::Named {
+                inner: Inner::Value(0),
+            },
+            Outer::Manual(Inner::Fail),
+        ]
+        .into_iter()
+        .find(|error| error.code().as_ref() == code);
+        assert!(error.is_some(), "no variant with code {code:?}");
+    }
+}
+
+/// An error implemented by hand, carrying an I/O error as its source.
+#[derive(Debug)]
+struct ReadError(std::io::Error);
+
+impl core::fmt::Display for ReadError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("cannot read the config")
+    }
+}
+
+impl core::error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+impl ErrorType for ReadError {
+    type Span = SimpleSpan;
+    type Kind = Kind;
+    type Message = String;
+    type Label = String;
+
+    fn kind(&self) -> Kind {
+        Kind::Error
+    }
+    fn number(&self) -> Cow<'_, str> {
+        Cow::Borrowed("5")
+    }
+    fn primary_span(&self) -> Option<SimpleSpan> {
+        None
+    }
+    fn primary_message(&self) -> String {
+        self.to_string()
+    }
+    fn primary_labels(&self) -> LabelVec1<SimpleSpan, String> {
+        vec1![(SimpleSpan::default(), self.to_string())]
+    }
+    fn additional(
+        &self,
+    ) -> Box<dyn Iterator<Item = (String, LabelVec1<SimpleSpan, String>, AdditionalKind)>> {
+        Box::new([].into_iter())
+    }
+}
+
+error_type! {
+    #[derive(Debug)]
+    ConfigError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "1")]
+            #[diag(nested)]
+            Read(ReadError),
+        }
+    }
+}
+
+#[test]
+fn nested_source() {
+    use core::error::Error;
+
+    let error = ConfigError::Read(ReadError(std::io::Error::other("permission denied")));
+    assert_eq!(error.to_string(), "cannot read the config");
+    assert_eq!(error.source().unwrap().to_string(), "permission denied");
+}
+
+#[test]
+fn hand_written_codes() {
+    // `ReadError` keeps the default `kinds_and_numbers`, so it contributes no codes.
+    assert!(ConfigError::all_codes().is_empty());
+}
+
+#[test]
+#[cfg(feature = "miette")]
+fn nested_source_miette() {
+    use error_enum::ErrorTypeExt;
+    use miette::NarratableReportHandler;
+
+    let error = ConfigError::Read(ReadError(std::io::Error::other("permission denied")));
+    let s = error.fmt_as_miette_diagnostic_with(&NarratableReportHandler::new());
+    assert_eq!(
+        s,
+        "\
+cannot read the config
+    Diagnostic severity: error
+    Caused by: permission denied
+diagnostic code: E15
+",
+    );
+}
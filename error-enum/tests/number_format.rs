@@ -0,0 +1,37 @@
+//! Tests for error numbers derived from variant order.
+
+use error_enum::{error_type, ErrorType};
+
+error_type! {
+    #[derive(Debug)]
+    #[diag(number_format = "{:03}")]
+    pub NumberedError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(msg = "first")]
+            First,
+            #[diag(msg = "second")]
+            Second,
+        },
+        #[diag(kind = "Warn")]
+        {
+            #[diag(msg = "third")]
+            Third,
+            #[diag(number = "9")]
+            #[diag(msg = "explicit")]
+            Explicit,
+            #[diag(msg = "fifth")]
+            Fifth,
+        },
+    }
+}
+
+#[test]
+fn zero_padded_codes_from_order() {
+    assert_eq!(NumberedError::First.code(), "E000");
+    assert_eq!(NumberedError::Second.code(), "E001");
+    assert_eq!(NumberedError::Third.code(), "W002");
+    assert_eq!(NumberedError::Explicit.code(), "W9");
+    assert_eq!(NumberedError::Fifth.code(), "W004");
+    assert_eq!(NumberedError::Fifth.number(), "004");
+}
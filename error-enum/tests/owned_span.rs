@@ -0,0 +1,68 @@
+//! Tests for errors carrying an [`OwnedSpan`].
+
+#![allow(clippy::unwrap_used)]
+
+use error_enum::{error_type, ErrorType, OwnedSpan, Span};
+
+error_type! {
+    #[derive(Debug)]
+    #[diag(span_type = "OwnedSpan")]
+    pub OwnedError {
+        #[diag(kind = "error")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "All in {white}.")]
+            #[diag(label = "check the color here")]
+            WhiteError {
+                /// Color name
+                white: String,
+                /// Span
+                #[diag(span)]
+                span: OwnedSpan,
+            },
+        },
+    }
+}
+
+#[test]
+fn owned_span() {
+    let span = OwnedSpan::new("foo.rs", "use white;\nfn main() {}", 4, 9);
+    assert_eq!(span.uri(), "foo.rs");
+    assert_eq!(&span.source_text()[span.range()], "white");
+    assert_eq!(span.clone(), span);
+    assert!(span.share_source_text(&span.clone()));
+
+    let error = OwnedError::WhiteError {
+        white: "white".into(),
+        span: span.clone(),
+    };
+    assert_eq!(error.primary_span().unwrap(), span);
+    assert_eq!(error.to_string(), "All in white.");
+}
+
+#[test]
+#[cfg(feature = "ariadne")]
+fn ariadne() {
+    use ariadne::Config;
+    use error_enum::ErrorTypeExt;
+
+    let error = OwnedError::WhiteError {
+        white: "white".into(),
+        span: OwnedSpan::new("foo.rs", "use white;", 4, 9),
+    };
+    let s = error
+        .fmt_as_ariadne_report_with(Config::new().with_color(false))
+        .unwrap();
+    assert_eq!(
+        s,
+        "\
+[E0] Error: All in white.
+   ╭─[ foo.rs:1:5 ]
+   │
+ 1 │ use white;
+   │     ──┬──  
+   │       ╰──── check the color here
+───╯
+",
+    );
+}
@@ -0,0 +1,47 @@
+//! Tests for the double-brace placeholder syntax.
+
+#![allow(clippy::unwrap_used)]
+
+use error_enum::{error_type, ErrorType, SimpleSpan};
+
+error_type! {
+    #[derive(Debug)]
+    #[diag(placeholder = "double_brace")]
+    pub TemplateError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "unknown key {{key}} in {a, b}")]
+            #[diag(label = "{{key:?}} is not one of {a, b}")]
+            UnknownKey {
+                /// The unknown key.
+                key: String,
+                /// Span of the key.
+                #[diag(span)]
+                #[diag(note("{{key}} was defined here"))]
+                span: SimpleSpan,
+            },
+            #[diag(number = "1")]
+            #[diag(msg = "{{code}}: expected {{0}} arguments, found {{1}}")]
+            Arity(usize, usize),
+        },
+    }
+}
+
+#[test]
+fn double_brace() {
+    let error = TemplateError::UnknownKey {
+        key: "c".into(),
+        span: SimpleSpan::new("a.tpl", "{c}", 1, 2),
+    };
+    assert_eq!(error.to_string(), "unknown key c in {a, b}");
+    assert_eq!(
+        error.primary_labels().first().1,
+        "\"c\" is not one of {a, b}"
+    );
+    let (note, _, _) = error.additional().next().unwrap();
+    assert_eq!(note, "c was defined here");
+
+    let error = TemplateError::Arity(2, 3);
+    assert_eq!(error.to_string(), "E1: expected 2 arguments, found 3");
+}
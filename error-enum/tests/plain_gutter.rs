@@ -0,0 +1,102 @@
+//! Tests for [`ErrorTypeExt::fmt_as_plain_with_gutter`].
+
+use error_enum::{error_type, ErrorTypeExt, SimpleSpan};
+
+error_type! {
+    #[derive(Debug)]
+    pub LintError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "0", msg = "undefined variable", label = "not found")]
+            Undefined(#[diag(span)] SimpleSpan),
+        },
+        #[diag(kind = "Warn")]
+        {
+            #[diag(number = "0", msg = "unused variable")]
+            Unused,
+        },
+    }
+}
+
+#[test]
+fn gutter() {
+    let source = "let x = 1;\nlet y = z;\nlet w = 2;\n";
+    let error = LintError::Undefined(SimpleSpan::new("main.rs", source, 19, 20));
+    assert_eq!(
+        error.fmt_as_plain_with_gutter(1, 1),
+        "\
+error[E0]: undefined variable
+ --> main.rs:2:9
+  |
+1 | let x = 1;
+2 | let y = z;
+  |         ^ not found
+3 | let w = 2;
+",
+    );
+    assert_eq!(
+        error.fmt_as_plain_with_gutter(0, 0),
+        "\
+error[E0]: undefined variable
+ --> main.rs:2:9
+  |
+2 | let y = z;
+  |         ^ not found
+",
+    );
+}
+
+#[test]
+fn gutter_width() {
+    let source: String = (1..=11).map(|i| format!("\tline{i}\n")).collect();
+    let start = source.find("line10").unwrap_or_default();
+    let error = LintError::Undefined(SimpleSpan::new(
+        "main.rs",
+        source.as_str(),
+        start,
+        start + 6,
+    ));
+    assert_eq!(
+        error.fmt_as_plain_with_gutter(1, 1),
+        "\
+error[E0]: undefined variable
+  --> main.rs:10:2
+   |
+ 9 | \tline9
+10 | \tline10
+   | \t^^^^^^ not found
+11 | \tline11
+",
+    );
+}
+
+#[test]
+#[cfg(feature = "unicode-width")]
+fn gutter_wide_chars() {
+    let source = "let 名前 = 値;\n";
+    let start = source.find('値').unwrap_or_default();
+    let error = LintError::Undefined(SimpleSpan::new(
+        "main.rs",
+        source,
+        start,
+        start + '値'.len_utf8(),
+    ));
+    assert_eq!(
+        error.fmt_as_plain_with_gutter(0, 0),
+        "\
+error[E0]: undefined variable
+ --> main.rs:1:10
+  |
+1 | let 名前 = 値;
+  |            ^^ not found
+",
+    );
+}
+
+#[test]
+fn without_span() {
+    assert_eq!(
+        LintError::Unused.fmt_as_plain_with_gutter(1, 1),
+        "warning[W0]: unused variable\n",
+    );
+}
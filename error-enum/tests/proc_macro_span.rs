@@ -0,0 +1,39 @@
+//! Tests for spans over `proc_macro2` tokens.
+
+#![cfg(feature = "proc-macro2")]
+#![allow(clippy::unwrap_used)]
+
+use core::str::FromStr;
+use error_enum::{error_type, ErrorTypeExt, ProcMacroSpan, Span};
+use proc_macro2::{TokenStream, TokenTree};
+
+error_type! {
+    #[derive(Debug)]
+    #[diag(span_type = "ProcMacroSpan")]
+    pub MacroError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "unexpected token")]
+            #[diag(label = "here")]
+            Unexpected(#[diag(span)] ProcMacroSpan),
+        },
+    }
+}
+
+#[test]
+fn proc_macro_span() {
+    let source = "struct Foo {\n    é: u8,\n    bar: u8,\n}\n";
+    let tokens = TokenStream::from_str(source).unwrap();
+    let Some(TokenTree::Group(body)) = tokens.into_iter().nth(2) else {
+        unreachable!()
+    };
+    let bar = body.stream().into_iter().nth(4).unwrap();
+    assert_eq!(bar.to_string(), "bar");
+
+    let span = ProcMacroSpan::new("foo.rs", source, bar.span());
+    assert_eq!(&span.source_text()[span.range()], "bar");
+
+    let error = MacroError::Unexpected(span);
+    assert_eq!(error.primary_location(), Some(("foo.rs".into(), 3, 5)));
+}
@@ -0,0 +1,38 @@
+//! Tests for `Error::provide` generated by `#[diag(provide)]`.
+//!
+//! Requires a nightly compiler: `RUSTFLAGS="--cfg error_generic_member_access" cargo +nightly test`.
+
+#![cfg(error_generic_member_access)]
+#![feature(error_generic_member_access)]
+
+use core::error::{request_value, Error};
+use error_enum::{error_type, SimpleSpan};
+
+error_type! {
+    #[derive(Debug)]
+    #[diag(provide)]
+    pub LexError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "unexpected character")]
+            Unexpected(#[diag(span)] SimpleSpan),
+            #[diag(number = "1")]
+            #[diag(msg = "unexpected end of file")]
+            UnexpectedEof,
+        },
+    }
+}
+
+#[test]
+fn provide_span() {
+    let span = SimpleSpan::new("main.rs", "let x = 1 $ 2;", 10, 11);
+    let error = LexError::Unexpected(span.clone());
+    let error: &dyn Error = &error;
+    assert_eq!(request_value::<SimpleSpan>(error), Some(span));
+    assert_eq!(request_value::<String>(error).as_deref(), Some("E0"));
+
+    let error: &dyn Error = &LexError::UnexpectedEof;
+    assert_eq!(request_value::<SimpleSpan>(error), None);
+    assert_eq!(request_value::<String>(error).as_deref(), Some("E1"));
+}
@@ -0,0 +1,135 @@
+//! Tests for [`RenderOptions`].
+
+#![allow(clippy::unwrap_used)]
+
+use core::cell::Cell;
+use error_enum::{
+    error_type, CachedRender, ErrorType, ErrorTypeExt, Kind, RenderOptions, Rendered,
+};
+
+error_type! {
+    #[derive(Debug)]
+    pub LintError {
+        #[diag(kind = "Warn")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "unused variable")]
+            Unused,
+        },
+    }
+}
+
+#[test]
+fn warnings_as_errors() {
+    let warning = LintError::Unused;
+    let options = RenderOptions::new().with_warnings_as_errors(true);
+    let rendered = warning.with_render_options(options);
+    assert_eq!(rendered.kind(), Kind::Error);
+    assert_eq!(rendered.code().as_ref(), "E0");
+    assert_eq!(rendered.primary_message(), warning.primary_message());
+
+    assert_eq!(warning.kind(), Kind::Warn);
+    assert_eq!(warning.code().as_ref(), "W0");
+
+    let rendered = warning.with_render_options(RenderOptions::default());
+    assert_eq!(rendered.kind(), Kind::Warn);
+    assert_eq!(rendered.code().as_ref(), "W0");
+}
+
+#[test]
+fn cached_render() {
+    let renders = Cell::new(0);
+    let render = |error: &Rendered<'_, LintError>| {
+        renders.set(renders.get() + 1);
+        format!("[{}] {}", ErrorType::code(error), error)
+    };
+    let cached = CachedRender::new(LintError::Unused);
+    let options = RenderOptions::new();
+
+    assert_eq!(
+        cached.render_with("plain", options, render),
+        "[W0] unused variable"
+    );
+    assert_eq!(
+        cached.render_with("plain", options, render),
+        "[W0] unused variable"
+    );
+    assert_eq!(renders.get(), 1);
+
+    let options = options.with_warnings_as_errors(true);
+    assert_eq!(
+        cached.render_with("plain", options, render),
+        "[E0] unused variable"
+    );
+    assert_eq!(renders.get(), 2);
+
+    cached.clear();
+    cached.render_with("plain", options, render);
+    assert_eq!(renders.get(), 3);
+}
+
+#[test]
+#[cfg(feature = "ariadne")]
+fn ariadne() {
+    use ariadne::Config;
+
+    let options = RenderOptions::new().with_warnings_as_errors(true);
+    let s = LintError::Unused
+        .with_render_options(options)
+        .fmt_as_ariadne_report_with(Config::new().with_color(false))
+        .unwrap();
+    assert!(s.starts_with("[E0] Error: unused variable\n"), "{s}");
+}
+
+#[test]
+#[cfg(feature = "codespan-reporting")]
+fn codespan_styles_by_kind() {
+    use codespan_reporting::term::{termcolor::Color, Config, Styles};
+
+    let default_styles = Styles::default();
+    let mut warning_styles = Styles::default();
+    warning_styles
+        .header_warning
+        .set_fg(Some(Color::Magenta))
+        .set_intense(false);
+    let styles_by_kind = |kind| match kind {
+        Kind::Warn => Some(&warning_styles),
+        Kind::Error => Some(&default_styles),
+        Kind::Info | Kind::Note | Kind::Help => None,
+    };
+
+    let warning = LintError::Unused
+        .fmt_as_codespan_diagnostic_with_styles(Config::default(), styles_by_kind)
+        .unwrap();
+    assert!(warning.contains("\x1b[35mwarning[W0]"), "{warning:?}");
+
+    let options = RenderOptions::new().with_warnings_as_errors(true);
+    let error = LintError::Unused
+        .with_render_options(options)
+        .fmt_as_codespan_diagnostic_with_styles(Config::default(), styles_by_kind)
+        .unwrap();
+    assert!(!error.contains("\x1b[35m"), "{error:?}");
+    assert!(error.contains("error[E0]"), "{error:?}");
+}
+
+#[test]
+#[cfg(feature = "codespan-reporting")]
+fn codespan_code_in_header() {
+    use codespan_reporting::term::{Config, Styles};
+
+    let plain = LintError::Unused
+        .fmt_as_codespan_diagnostic_with(Config::default(), None)
+        .unwrap();
+    assert!(
+        plain.starts_with("warning[W0]: unused variable\n"),
+        "{plain:?}"
+    );
+
+    let styles = Styles::default();
+    let styled = LintError::Unused
+        .fmt_as_codespan_diagnostic_with(Config::default(), Some(&styles))
+        .unwrap();
+    let header = styled.lines().next().unwrap();
+    assert!(header.contains("warning[W0]"), "{header:?}");
+    assert!(header.starts_with("\x1b["), "{header:?}");
+}
@@ -0,0 +1,54 @@
+//! Tests for rustc-compatible JSON output.
+
+#![cfg(feature = "rustc-json")]
+#![allow(clippy::unwrap_used)]
+
+use error_enum::{error_type, ErrorTypeExt, SimpleSpan};
+
+error_type! {
+    #[derive(Debug)]
+    pub LintError {
+        #[diag(kind = "Warn")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "unused variable `{name}`")]
+            #[diag(label = "declared here")]
+            Unused {
+                /// Variable name.
+                name: String,
+                /// Declaration.
+                #[diag(span)]
+                span: SimpleSpan,
+                /// Suggestion.
+                #[diag(help("prefix it with an underscore"))]
+                help: SimpleSpan,
+            },
+        },
+    }
+}
+
+#[test]
+fn rustc_json() {
+    let span = SimpleSpan::new("src/main.rs", "fn main() {\n    let é\"x = 1;\n}\n", 20, 24);
+    let error = LintError::Unused {
+        name: "é\"x".into(),
+        span: span.clone(),
+        help: span,
+    };
+    let json = error.fmt_as_rustc_json().unwrap();
+    assert!(!json.contains('\n'));
+    for field in [
+        r#""$message_type":"diagnostic""#,
+        r#""message":"unused variable `é\"x`""#,
+        r#""code":{"code":"W0","explanation":null}"#,
+        r#""level":"warning""#,
+        r#""file_name":"src/main.rs""#,
+        r#""byte_start":20,"byte_end":24"#,
+        r#""line_start":2,"line_end":2,"column_start":9,"column_end":12"#,
+        r#""is_primary":true"#,
+        r#""label":"declared here""#,
+        r#""children":[{"message":"prefix it with an underscore","code":null,"level":"help""#,
+    ] {
+        assert!(json.contains(field), "{field} not found in {json}");
+    }
+}
@@ -0,0 +1,100 @@
+//! Tests for variants marking several fields with `#[diag(span)]`.
+
+#![allow(clippy::unwrap_used)]
+
+use error_enum::{error_type, ErrorType, ErrorTypeExt, SimpleSpan, Span};
+
+error_type! {
+    #[derive(Debug)]
+    pub TypeError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "0", msg = "type `{name}` is private")]
+            Private {
+                /// Name of the type.
+                name: String,
+                /// Where the type is used.
+                #[diag(span, label = "used here")]
+                used: SimpleSpan,
+                /// Where the type is declared.
+                #[diag(span, label = "type declared here")]
+                declared: SimpleSpan,
+                /// Where the visibility could be changed.
+                #[diag(span, label("consider `pub` here"))]
+                visibility: SimpleSpan,
+            },
+        },
+    }
+}
+
+const SOURCE: &str = "struct Foo;\nfn f(_: Foo) {}\n";
+
+fn error() -> TypeError {
+    TypeError::Private {
+        name: "Foo".into(),
+        used: SimpleSpan::new("lib.rs", SOURCE, 20, 23),
+        declared: SimpleSpan::new("lib.rs", SOURCE, 7, 10),
+        visibility: SimpleSpan::new("lib.rs", SOURCE, 0, 0),
+    }
+}
+
+#[test]
+fn secondary_spans() {
+    let error = error();
+    assert_eq!(error.primary_span().unwrap().range(), 20..23);
+    assert_eq!(error.primary_labels().first().1, "used here");
+    let secondary: Vec<_> = error
+        .secondary_spans()
+        .into_iter()
+        .map(|(span, label)| (span.range(), label))
+        .collect();
+    assert_eq!(
+        secondary,
+        [
+            (7..10, "type declared here".to_owned()),
+            (0..0, "consider `pub` here".to_owned()),
+        ],
+    );
+}
+
+#[test]
+#[cfg(feature = "codespan-reporting")]
+fn codespan() {
+    let s = error()
+        .fmt_as_codespan_diagnostic_with(codespan_reporting::term::Config::default(), None)
+        .unwrap();
+    assert!(s.contains("used here"), "{s}");
+    assert!(s.contains("type declared here"), "{s}");
+}
+
+#[test]
+#[cfg(feature = "ariadne")]
+fn ariadne() {
+    let s = error()
+        .fmt_as_ariadne_report_with(ariadne::Config::new().with_color(false))
+        .unwrap();
+    assert!(s.contains("used here"), "{s}");
+    assert!(s.contains("type declared here"), "{s}");
+}
+
+#[test]
+#[cfg(feature = "miette")]
+fn miette() {
+    use miette::Diagnostic as _;
+
+    let error = error();
+    let diagnostic = error.as_miette_diagnostic();
+    let labels: Vec<_> = diagnostic
+        .labels()
+        .unwrap()
+        .map(|label| label.label().unwrap_or_default().to_owned())
+        .collect();
+    assert!(
+        labels.iter().any(|label| label == "used here"),
+        "{labels:?}"
+    );
+    assert!(
+        labels.iter().any(|label| label == "type declared here"),
+        "{labels:?}"
+    );
+}
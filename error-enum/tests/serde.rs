@@ -0,0 +1,59 @@
+//! Tests for serde derives passed through `error_type!`.
+
+#![allow(clippy::unwrap_used)]
+
+use error_enum::{error_type, ErrorType};
+use serde::{Deserialize, Serialize};
+
+error_type! {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    #[serde(tag = "type")]
+    #[diag(kind = "Error")]
+    pub IpcError {
+        #[diag(number = "0")]
+        {
+            #[diag(number = "1")]
+            #[diag(msg = "{path} not found")]
+            #[diag(note("checked {count} directories"))]
+            FileNotFound {
+                /// Path of the missing file.
+                path: String,
+                /// Number of directories searched.
+                #[serde(default)]
+                count: u32,
+            },
+            #[diag(number = "2")]
+            #[diag(msg = "connection closed")]
+            Closed,
+        },
+    }
+}
+
+#[test]
+fn serde_round_trip() {
+    let error = IpcError::FileNotFound {
+        path: "a.txt".into(),
+        count: 3,
+    };
+    let json = serde_json::to_string(&error).unwrap();
+    assert_eq!(json, r#"{"type":"FileNotFound","path":"a.txt","count":3}"#);
+    let back: IpcError = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, error);
+    assert_eq!(back.code(), "E01");
+    assert_eq!(back.to_string(), "a.txt not found");
+
+    let back: IpcError = serde_json::from_str(r#"{"type":"FileNotFound","path":"b.txt"}"#).unwrap();
+    assert_eq!(
+        back,
+        IpcError::FileNotFound {
+            path: "b.txt".into(),
+            count: 0,
+        }
+    );
+
+    let json = serde_json::to_string(&IpcError::Closed).unwrap();
+    assert_eq!(
+        serde_json::from_str::<IpcError>(&json).unwrap(),
+        IpcError::Closed
+    );
+}
@@ -0,0 +1,80 @@
+//! Tests for fine-grained severity levels from `#[diag(level = N)]`.
+
+#![allow(clippy::unwrap_used)]
+
+use error_enum::{error_type, ErrorType, ErrorTypeExt, Kind, RenderOptions};
+
+error_type! {
+    #[derive(Debug)]
+    pub LintError {
+        #[diag(kind = "Warn")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "unused variable")]
+            Unused,
+            #[diag(number = "1")]
+            #[diag(msg = "consider a shorter name")]
+            #[diag(level = 2)]
+            LongName,
+            #[diag(level = 3)]
+            {
+                #[diag(number = "2")]
+                #[diag(msg = "style preference")]
+                Style,
+            },
+        },
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "0")]
+            #[diag(nested)]
+            Nested(InnerError),
+        },
+    }
+}
+
+error_type! {
+    #[derive(Debug)]
+    pub InnerError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "inner note")]
+            #[diag(level = 2)]
+            Note,
+        },
+    }
+}
+
+#[test]
+fn severity_level() {
+    assert_eq!(LintError::Unused.severity_level(), 1);
+    assert_eq!(LintError::LongName.kind(), Kind::Warn);
+    assert_eq!(LintError::LongName.severity_level(), 2);
+    assert_eq!(LintError::Style.severity_level(), 3);
+    assert_eq!(LintError::Nested(InnerError::Note).severity_level(), 2);
+
+    let options = RenderOptions::new().with_warnings_as_errors(true);
+    assert_eq!(
+        LintError::Unused
+            .with_render_options(options)
+            .severity_level(),
+        0
+    );
+    assert_eq!(
+        LintError::LongName
+            .with_render_options(options)
+            .severity_level(),
+        2
+    );
+}
+
+#[test]
+#[cfg(feature = "rustc-json")]
+fn rustc_json_level() {
+    let json = LintError::Unused.fmt_as_rustc_json().unwrap();
+    assert!(json.contains("\"level\":\"warning\""), "{json}");
+    let json = LintError::LongName.fmt_as_rustc_json().unwrap();
+    assert!(json.contains("\"level\":\"note\""), "{json}");
+    let json = LintError::Style.fmt_as_rustc_json().unwrap();
+    assert!(json.contains("\"level\":\"help\""), "{json}");
+}
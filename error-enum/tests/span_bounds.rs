@@ -0,0 +1,34 @@
+//! Tests for the debug assertion on spans outside their source text.
+
+#![cfg(all(debug_assertions, feature = "rustc-json"))]
+#![allow(clippy::unwrap_used)]
+
+use error_enum::{error_type, ErrorTypeExt, SimpleSpan};
+
+error_type! {
+    #[derive(Debug)]
+    pub ParseError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "unexpected end of file")]
+            UnexpectedEof(#[diag(span)] SimpleSpan),
+        },
+    }
+}
+
+const SOURCE: &str = "fn main() {";
+
+#[test]
+fn end_of_file_span() {
+    let error = ParseError::UnexpectedEof(SimpleSpan::new("main.rs", SOURCE, 11, 11));
+    let json = error.fmt_as_rustc_json().unwrap();
+    assert!(json.contains("\"byte_start\":11"), "{json}");
+}
+
+#[test]
+#[should_panic = "span 11..12 is out of bounds of `main.rs` (11 bytes)"]
+fn out_of_bounds_span() {
+    let error = ParseError::UnexpectedEof(SimpleSpan::new("main.rs", SOURCE, 11, 12));
+    let _ = error.fmt_as_rustc_json();
+}
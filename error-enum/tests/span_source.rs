@@ -0,0 +1,151 @@
+//! Tests for spans built from a byte range and a separately held source.
+
+#![allow(clippy::unwrap_used)]
+
+use core::ops::Range;
+use error_enum::{error_type, ErrorType, ErrorTypeExt, SimpleSpan, Span};
+
+error_type! {
+    #[derive(Debug)]
+    pub LexError {
+        #[diag(kind = "Error")]
+        #[diag(source = "file")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "unexpected character")]
+            #[diag(label = "not allowed here")]
+            Unexpected {
+                /// The whole source file.
+                file: SimpleSpan,
+                /// Token range produced by the lexer.
+                #[diag(span)]
+                range: Range<usize>,
+                /// Range of the enclosing item.
+                #[diag(note("inside this item"))]
+                item: Range<usize>,
+            },
+            #[diag(number = "1")]
+            #[diag(msg = "unterminated string")]
+            #[diag(label = "string starts here")]
+            Unterminated {
+                /// The whole source file.
+                file: SimpleSpan,
+                /// Offset pair produced by the lexer.
+                #[diag(span)]
+                offsets: (usize, usize),
+            },
+        },
+    }
+}
+
+error_type! {
+    #[derive(Debug)]
+    pub TypeError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "`{term}` is of type `{actual_ty}`")]
+            Mismatch {
+                /// The term.
+                term: String,
+                /// Actual type.
+                actual_ty: String,
+                /// Span of the term.
+                #[diag(span)]
+                span: SimpleSpan,
+            },
+        },
+    }
+}
+
+error_type! {
+    #[derive(Debug)]
+    pub EofError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "unexpected end of file")]
+            #[diag(label = "file ends here")]
+            UnexpectedEof(#[diag(span)] SimpleSpan),
+        },
+    }
+}
+
+#[test]
+fn span_from_range() {
+    let file = SimpleSpan::new("main.rs", "let x = 1 $ 2;", 0, 0);
+    let error = LexError::Unexpected {
+        file: file.clone(),
+        range: 10..11,
+        item: 0..14,
+    };
+
+    let span = error.primary_span().unwrap();
+    assert_eq!(span.uri().as_ref(), "main.rs");
+    assert_eq!(span.range(), 10..11);
+    assert_eq!(&span.source_text()[span.range()], "$");
+    assert_eq!(span, file.with_range(10, 11));
+
+    let (_, labels, _) = error.additional().next().unwrap();
+    assert_eq!(labels.first().0.range(), 0..14);
+}
+
+#[test]
+fn span_from_offset_pair() {
+    let file = SimpleSpan::new("main.rs", "let s = \"abc;", 0, 0);
+    let error = LexError::Unterminated {
+        file: file.clone(),
+        offsets: (8, 9),
+    };
+
+    let span = error.primary_span().unwrap();
+    assert_eq!(span.range(), 8..9);
+    assert_eq!(&span.source_text()[span.range()], "\"");
+    assert!(span.share_source_text(&file));
+    assert_eq!(span, SimpleSpan::from((&file, (8, 9))));
+}
+
+#[test]
+fn simple_span_from_range() {
+    let file = SimpleSpan::new("main.rs", "let x = 1 $ 2;", 0, 0);
+    let span = SimpleSpan::from((&file, 4..5));
+    assert_eq!(span.range(), 4..5);
+    assert!(span.share_source_text(&file));
+}
+
+#[test]
+fn primary_span_text() {
+    let source = "print(1 + 2)\nprint(1 + '1')\nprint('1' + '1')";
+    let error = TypeError::Mismatch {
+        term: "'1'".into(),
+        actual_ty: "str".into(),
+        span: SimpleSpan::new("file://test.py", source, 23, 26),
+    };
+    assert_eq!(error.primary_span_text().as_deref(), Some("'1'"));
+
+    let error = TypeError::Mismatch {
+        term: "'1'".into(),
+        actual_ty: "str".into(),
+        span: SimpleSpan::new("file://test.py", source, 40, 100),
+    };
+    assert_eq!(error.primary_span_text().as_deref(), Some("'1')"));
+
+    let error = TypeError::Mismatch {
+        term: "'1'".into(),
+        actual_ty: "str".into(),
+        span: SimpleSpan::default(),
+    };
+    assert_eq!(error.primary_span_text(), None);
+}
+
+#[test]
+fn span_only_tuple_variant() {
+    let span = SimpleSpan::new("main.rs", "fn main() {", 11, 11);
+    let error = EofError::UnexpectedEof(span.clone());
+    assert_eq!(error.to_string(), "unexpected end of file");
+    assert_eq!(error.primary_span(), Some(span.clone()));
+    assert_eq!(
+        error.primary_labels().first(),
+        &(span, "file ends here".into())
+    );
+}
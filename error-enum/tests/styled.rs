@@ -0,0 +1,86 @@
+//! Tests for styled output through `owo-colors`.
+
+#![cfg(feature = "owo-colors")]
+
+use error_enum::{error_type, ErrorTypeExt, SimpleSpan, StyleSheet};
+use owo_colors::Style;
+
+error_type! {
+    #[derive(Debug)]
+    pub LintError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "unknown lint `{name}`")]
+            UnknownLint {
+                /// Lint name.
+                name: String,
+                /// Lint.
+                #[diag(span)]
+                span: SimpleSpan,
+            },
+        },
+        #[diag(kind = "Warn")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "lint `{name}` is deprecated")]
+            DeprecatedLint {
+                /// Lint name.
+                name: String,
+            },
+            #[diag(number = "1")]
+            #[diag(level = 2)]
+            #[diag(msg = "lint `{name}` is allowed by default")]
+            AllowedLint {
+                /// Lint name.
+                name: String,
+            },
+        },
+    }
+}
+
+#[test]
+fn styled() {
+    owo_colors::set_override(true);
+
+    let error = LintError::UnknownLint {
+        name: "unused".into(),
+        span: SimpleSpan::new("lib.rs", "#![deny(unused)]", 8, 14),
+    };
+    assert_eq!(
+        error.fmt_as_styled(),
+        "\x1b[31;1merror[E0]\x1b[0m: \x1b[1munknown lint `unused`\x1b[0m \x1b[34m(lib.rs:1:9)\x1b[0m",
+    );
+
+    let warning = LintError::DeprecatedLint {
+        name: "unused".into(),
+    };
+    assert_eq!(
+        warning.fmt_as_styled(),
+        "\x1b[33;1mwarning[W0]\x1b[0m: \x1b[1mlint `unused` is deprecated\x1b[0m",
+    );
+
+    let styles = StyleSheet::new()
+        .with_warning(Style::new().magenta())
+        .with_message(Style::new());
+    assert_eq!(
+        warning.fmt_as_styled_with(&styles),
+        "\x1b[35mwarning[W0]\x1b[0m: lint `unused` is deprecated",
+    );
+
+    // Styled by the severity level of the variant rather than of its kind.
+    let allowed = LintError::AllowedLint {
+        name: "unused".into(),
+    };
+    assert_eq!(
+        allowed.fmt_as_styled(),
+        "\x1b[36;1mwarning[W1]\x1b[0m: \x1b[1mlint `unused` is allowed by default\x1b[0m",
+    );
+
+    owo_colors::set_override(false);
+    assert_eq!(
+        warning.fmt_as_styled(),
+        "warning[W0]: lint `unused` is deprecated",
+    );
+    owo_colors::unset_override();
+}
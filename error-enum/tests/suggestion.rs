@@ -0,0 +1,102 @@
+//! Tests for `#[diag(suggest = "...")]` replacement suggestions.
+
+#![allow(clippy::unwrap_used)]
+
+use error_enum::{error_type, ErrorType, SimpleSpan, Span};
+
+error_type! {
+    #[derive(Debug)]
+    pub SyntaxError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "unknown keyword `{found}`")]
+            #[diag(suggest = "{expected}")]
+            UnknownKeyword {
+                /// Keyword in the source.
+                found: String,
+                /// Keyword to use instead.
+                expected: String,
+                /// Span of the keyword.
+                #[diag(span)]
+                span: SimpleSpan,
+            },
+            #[diag(number = "1")]
+            #[diag(msg = "missing semicolon")]
+            #[diag(suggest = "{0};")]
+            MissingSemicolon(String, #[diag(span)] SimpleSpan),
+            #[diag(number = "2")]
+            #[diag(msg = "unexpected end of file")]
+            UnexpectedEof(#[diag(span)] SimpleSpan),
+            #[diag(number = "3")]
+            #[diag(nested)]
+            Inner(InnerError),
+        },
+    }
+}
+
+error_type! {
+    #[derive(Debug)]
+    pub InnerError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "use `{expected}` here")]
+            #[diag(suggest = "{expected}")]
+            Replace {
+                /// Replacement text.
+                expected: String,
+                /// Span to replace.
+                #[diag(span)]
+                span: SimpleSpan,
+            },
+        },
+    }
+}
+
+const SOURCE: &str = "fun main() {\n    let x = 1\n}";
+
+#[test]
+fn suggestion() {
+    let span = SimpleSpan::new("main.rs", SOURCE, 0, 3);
+    let error = SyntaxError::UnknownKeyword {
+        found: "fun".into(),
+        expected: "fn".into(),
+        span: span.clone(),
+    };
+    assert_eq!(error.suggestion(), Some((span, "fn".into())));
+
+    let span = SimpleSpan::new("main.rs", SOURCE, 17, 26);
+    let error = SyntaxError::MissingSemicolon("let x = 1".into(), span.clone());
+    let (suggested, text) = error.suggestion().unwrap();
+    assert_eq!(suggested.range(), 17..26);
+    assert_eq!(text, "let x = 1;");
+
+    let error = SyntaxError::UnexpectedEof(SimpleSpan::new("main.rs", SOURCE, 29, 29));
+    assert_eq!(error.suggestion(), None);
+}
+
+#[test]
+fn nested_suggestion() {
+    let span = SimpleSpan::new("main.rs", SOURCE, 0, 3);
+    let error = SyntaxError::Inner(InnerError::Replace {
+        expected: "fn".into(),
+        span: span.clone(),
+    });
+    assert_eq!(error.suggestion(), Some((span, "fn".into())));
+}
+
+#[test]
+#[cfg(feature = "miette")]
+fn suggestion_miette() {
+    use error_enum::ErrorTypeExt;
+    use miette::NarratableReportHandler;
+
+    let error = SyntaxError::UnknownKeyword {
+        found: "fun".into(),
+        expected: "fn".into(),
+        span: SimpleSpan::new("main.rs", SOURCE, 0, 3),
+    };
+    let s = error.fmt_as_miette_diagnostic_with(&NarratableReportHandler::new());
+    assert!(s.contains("help: replace with `fn`"), "{s}");
+}
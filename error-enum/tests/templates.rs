@@ -0,0 +1,40 @@
+//! Tests for message templates shared across variants.
+
+use error_enum::{error_type, ErrorType};
+
+error_type! {
+    #[derive(Debug)]
+    #[diag(templates(internal = "internal error: {0}", unsupported = "`{feature}` is not supported"))]
+    pub CompilerError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg_ref = "internal")]
+            Lowering(String),
+            #[diag(number = "1")]
+            #[diag(msg_ref = "internal")]
+            Codegen(u32),
+            #[diag(number = "2")]
+            #[diag(msg_ref = "unsupported")]
+            Unsupported {
+                /// Name of the feature.
+                feature: String,
+            },
+        },
+    }
+}
+
+#[test]
+fn shared_template() {
+    let lowering = CompilerError::Lowering("missing type".into());
+    let codegen = CompilerError::Codegen(42);
+    assert_eq!(lowering.to_string(), "internal error: missing type");
+    assert_eq!(codegen.to_string(), "internal error: 42");
+    assert_eq!(lowering.message_template(), "internal error: {0}");
+    assert_eq!(codegen.message_template(), "internal error: {0}");
+
+    let unsupported = CompilerError::Unsupported {
+        feature: "async".into(),
+    };
+    assert_eq!(unsupported.to_string(), "`async` is not supported");
+}
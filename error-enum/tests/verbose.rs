@@ -0,0 +1,68 @@
+//! Tests for alternate messages per output verbosity.
+
+#![allow(clippy::unwrap_used)]
+
+use error_enum::{error_type, ErrorType, ErrorTypeExt, SimpleSpan};
+
+error_type! {
+    #[derive(Debug)]
+    pub ResolveError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "0")]
+            #[diag(msg = "unresolved `{name}`")]
+            #[diag(msg_verbose = "unresolved `{name}`; consider importing it or checking the spelling")]
+            #[diag(label = "not found in this scope")]
+            Unresolved {
+                /// Name of the unresolved item.
+                name: String,
+                /// Span of the name.
+                #[diag(span)]
+                span: SimpleSpan,
+            },
+            #[diag(number = "1")]
+            #[diag(msg = "cyclic import")]
+            Cycle,
+        },
+    }
+}
+
+#[test]
+fn verbose_message() {
+    let error = ResolveError::Unresolved {
+        name: "foo".into(),
+        span: SimpleSpan::new("main.rs", "foo();", 0, 3),
+    };
+    assert_eq!(error.primary_message(), "unresolved `foo`");
+    assert_eq!(
+        error.primary_message_verbose(),
+        "unresolved `foo`; consider importing it or checking the spelling"
+    );
+    assert_eq!(
+        error.fmt_compact(),
+        "error[E0]: unresolved `foo` (main.rs:1:1)"
+    );
+}
+
+#[test]
+fn verbose_message_fallback() {
+    let error = ResolveError::Cycle;
+    assert_eq!(error.primary_message_verbose(), error.primary_message());
+}
+
+#[test]
+#[cfg(feature = "codespan-reporting")]
+fn codespan_reporting() {
+    use codespan_reporting::term::Config;
+
+    let error = ResolveError::Unresolved {
+        name: "foo".into(),
+        span: SimpleSpan::new("main.rs", "foo();", 0, 3),
+    };
+    let s = error
+        .fmt_as_codespan_diagnostic_with(Config::default(), None)
+        .unwrap();
+    assert!(s.starts_with(
+        "error[E0]: unresolved `foo`; consider importing it or checking the spelling\n"
+    ));
+}
@@ -0,0 +1,54 @@
+//! Tests for the dependency-free JSON output.
+
+#![cfg(feature = "std")]
+#![allow(clippy::unwrap_used)]
+
+use error_enum::{error_type, ErrorTypeExt, SimpleSpan};
+
+error_type! {
+    #[derive(Debug)]
+    pub FileSystemError {
+        #[diag(kind = "Error")]
+        {
+            #[diag(number = "01")]
+            #[diag(msg = "{path} not found")]
+            FileNotFound {
+                /// Path of the missing file.
+                path: String,
+                /// Span of the path.
+                #[diag(span)]
+                span: SimpleSpan,
+            },
+        },
+        #[diag(kind = "Warn")]
+        {
+            #[diag(number = "01")]
+            #[diag(msg = "{0} is a symbolic link")]
+            SymbolicLink(String),
+        },
+    }
+}
+
+fn to_json(error: &FileSystemError) -> String {
+    let mut out = Vec::new();
+    error.write_json(&mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn write_json() {
+    let error = FileSystemError::FileNotFound {
+        path: r#""C:\tmp"	x"#.into(),
+        span: SimpleSpan::new("main.rs", "open(\"C:\\tmp\")", 5, 13),
+    };
+    assert_eq!(
+        to_json(&error),
+        r#"{"code":"E01","kind":"error","message":"\"C:\\tmp\"\tx not found","uri":"main.rs","start":5,"end":13}"#,
+    );
+
+    let error = FileSystemError::SymbolicLink("a\nb".into());
+    assert_eq!(
+        to_json(&error),
+        r#"{"code":"W01","kind":"warning","message":"a\nb is a symbolic link","uri":null,"start":null,"end":null}"#,
+    );
+}